@@ -0,0 +1,95 @@
+use std::{env, fmt::Write, fs, path::Path};
+
+/// Generates one test per example input and part, see tests/examples.rs, and the C header of the
+/// `cdylib`, which src/ffi.rs checks against the committed include/aoc.h.
+fn main() {
+    let out_dir = env::var("OUT_DIR").unwrap();
+    println!("cargo:rerun-if-changed=src/ffi.rs");
+    header(Path::new("src/ffi.rs"), &Path::new(&out_dir).join("aoc.h"));
+
+    println!("cargo:rerun-if-changed=examples");
+    let mut tests = String::new();
+    for (year, day, example, parts) in examples(Path::new("examples")) {
+        for part in parts {
+            writeln!(
+                tests,
+                "#[test]\nfn y{year}_day{day}_{example}_part{part}() {{\n    check({year}, {day}, {example:?}, {part});\n}}\n"
+            )
+            .unwrap();
+        }
+    }
+    let out = Path::new(&out_dir).join("examples.rs");
+    fs::write(out, tests).unwrap();
+}
+
+/// Declares the C interface of `source` in `header`, which is only rewritten when it changes.
+fn header(source: &Path, header: &Path) {
+    let config = cbindgen::Config {
+        language: cbindgen::Language::C,
+        header: Some(
+            "/* Generated from src/ffi.rs by cbindgen in build.rs, do not edit. */".to_string(),
+        ),
+        include_guard: Some("AOC_H".to_string()),
+        cpp_compat: true,
+        usize_is_size_t: true,
+        enumeration: cbindgen::EnumConfig {
+            rename_variants: cbindgen::RenameRule::ScreamingSnakeCase,
+            prefix_with_name: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    cbindgen::Builder::new()
+        .with_config(config)
+        .with_src(source)
+        .generate()
+        .unwrap_or_else(|e| panic!("{}: {}", source.display(), e))
+        .write_to_file(header);
+}
+
+/// Lists the examples as `examples/<year>/day<day>/<example>.txt`, with the parts that have an
+/// expected answer in the `<example>.toml` next to them.
+fn examples(dir: &Path) -> Vec<(u16, u8, String, Vec<u8>)> {
+    let mut examples = Vec::new();
+    for (year, year_dir) in entries(dir, "") {
+        for (day, day_dir) in entries(&year_dir, "day") {
+            for entry in fs::read_dir(&day_dir).unwrap() {
+                let path = entry.unwrap().path();
+                if path.extension().is_none_or(|ext| ext != "toml") {
+                    continue;
+                }
+                println!("cargo:rerun-if-changed={}", path.display());
+                let expected = fs::read_to_string(&path)
+                    .unwrap()
+                    .parse::<toml::Table>()
+                    .unwrap_or_else(|e| panic!("{}: {}", path.display(), e));
+                let parts = [1, 2]
+                    .into_iter()
+                    .filter(|part| expected.contains_key(&format!("part{part}")))
+                    .collect();
+                let example = path.file_stem().unwrap().to_string_lossy().into_owned();
+                examples.push((year.parse().unwrap(), day.parse().unwrap(), example, parts));
+            }
+        }
+    }
+    examples.sort();
+    examples
+}
+
+fn entries(dir: &Path, prefix: &str) -> Vec<(String, std::path::PathBuf)> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    entries
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.is_dir())
+        .filter_map(|path| {
+            let name = path
+                .file_name()?
+                .to_str()?
+                .strip_prefix(prefix)?
+                .to_string();
+            name.parse::<u16>().is_ok().then_some((name, path))
+        })
+        .collect()
+}
@@ -0,0 +1,92 @@
+/* Generated from src/ffi.rs by cbindgen in build.rs, do not edit. */
+
+#ifndef AOC_H
+#define AOC_H
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * What [`aoc_solve`] did.
+ */
+typedef enum AocStatus {
+  /**
+   * The answer was written to the output buffer.
+   */
+  AOC_STATUS_OK = 0,
+  /**
+   * No day is registered for this year and day.
+   */
+  AOC_STATUS_UNKNOWN_DAY = 1,
+  /**
+   * The part isn't 1 or 2, or a pointer is null with a length that isn't 0.
+   */
+  AOC_STATUS_INVALID_ARGUMENT = 2,
+  /**
+   * The input couldn't be parsed, and the error was written to the output buffer.
+   */
+  AOC_STATUS_PARSE_ERROR = 3,
+  /**
+   * The part has no answer for this input, and the error was written to the output buffer.
+   */
+  AOC_STATUS_NO_SOLUTION = 4,
+  /**
+   * The day panicked, and the panic message was written to the output buffer.
+   */
+  AOC_STATUS_PANIC = 5,
+  /**
+   * The answer didn't fit in the output buffer, which holds as much of it as fits.
+   */
+  AOC_STATUS_BUFFER_TOO_SMALL = 6,
+} AocStatus;
+
+/**
+ * A day that can be solved, as listed by [`aoc_days`].
+ */
+typedef struct AocDay {
+  uint16_t year;
+  uint8_t day;
+} AocDay;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Writes the first `capacity` days that can be solved to `days`, in order, and returns how many
+ * there are in all.
+ *
+ * # Safety
+ *
+ * `days` must point to `capacity` writable days. It can be null when `capacity` is 0, to count
+ * the days before allocating room for them.
+ */
+size_t aoc_days(struct AocDay *days, size_t capacity);
+
+/**
+ * Solves a part of a day on the `input_len` bytes at `input_ptr`.
+ *
+ * The answer is written as a NUL-terminated string to `out_buf`, `out_len` bytes long with the
+ * NUL. Parse errors, missing solutions and panics are written there too, cut short if needed.
+ *
+ * # Safety
+ *
+ * `input_ptr` must point to `input_len` readable bytes and `out_buf` to `out_len` writable ones.
+ * Either can be null when its length is 0.
+ */
+enum AocStatus aoc_solve(uint16_t year,
+                         uint8_t day,
+                         uint8_t part,
+                         const uint8_t *input_ptr,
+                         size_t input_len,
+                         char *out_buf,
+                         size_t out_len);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* AOC_H */
@@ -0,0 +1,168 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Write,
+    io::{Error, ErrorKind},
+    path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+
+/// Known answers of a year, keyed by day, then by input hash, then by part.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Answers {
+    #[serde(flatten)]
+    days: BTreeMap<String, BTreeMap<String, Parts>>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct Parts {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part2: Option<String>,
+}
+
+impl Parts {
+    const fn get_mut(&mut self, part: u8) -> &mut Option<String> {
+        if part == 1 {
+            &mut self.part1
+        } else {
+            &mut self.part2
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String },
+    Unchecked,
+    Recorded,
+}
+
+impl Answers {
+    #[must_use]
+    pub fn path(year: u16) -> PathBuf {
+        PathBuf::from(format!("answers/{year}.toml"))
+    }
+
+    /// Loads the answers stored at `path`, with a missing file holding no answers.
+    pub fn load(path: &Path) -> std::io::Result<Self> {
+        match std::fs::read_to_string(path) {
+            Ok(content) => {
+                toml::from_str(&content).map_err(|e| Error::new(ErrorKind::InvalidData, e))
+            }
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> std::io::Result<()> {
+        let content =
+            toml::to_string_pretty(self).map_err(|e| Error::new(ErrorKind::InvalidData, e))?;
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(path, content)
+    }
+
+    #[must_use]
+    pub fn get(&self, day: u8, input_hash: &str, part: u8) -> Option<&str> {
+        let parts = self.days.get(&format!("day{day}"))?.get(input_hash)?;
+        if part == 1 {
+            parts.part1.as_deref()
+        } else {
+            parts.part2.as_deref()
+        }
+    }
+
+    /// Compares `answer` with the known one, storing it first if there is none and `record` is set.
+    pub fn verify(
+        &mut self,
+        day: u8,
+        input_hash: &str,
+        part: u8,
+        answer: &str,
+        record: bool,
+    ) -> Verdict {
+        match self.get(day, input_hash, part) {
+            Some(expected) if expected == answer => Verdict::Pass,
+            Some(expected) => Verdict::Fail {
+                expected: expected.to_string(),
+            },
+            None if record => {
+                *self
+                    .days
+                    .entry(format!("day{day}"))
+                    .or_default()
+                    .entry(input_hash.to_string())
+                    .or_default()
+                    .get_mut(part) = Some(answer.to_string());
+                Verdict::Recorded
+            }
+            None => Verdict::Unchecked,
+        }
+    }
+}
+
+/// Line by line comparison of two answers, in the style of a unified diff.
+#[must_use]
+pub fn diff(expected: &str, actual: &str) -> String {
+    let mut expected_lines = expected.lines();
+    let mut actual_lines = actual.lines();
+    let mut diff = String::new();
+    loop {
+        match (expected_lines.next(), actual_lines.next()) {
+            (None, None) => break,
+            (Some(e), Some(a)) if e == a => {
+                writeln!(diff, "  {e}").unwrap();
+            }
+            (e, a) => {
+                if let Some(e) = e {
+                    writeln!(diff, "- {e}").unwrap();
+                }
+                if let Some(a) = a {
+                    writeln!(diff, "+ {a}").unwrap();
+                }
+            }
+        }
+    }
+    diff
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn verify_and_record() {
+        let mut answers = Answers::default();
+        assert_eq!(answers.verify(1, "abc", 1, "42", false), Verdict::Unchecked);
+        assert_eq!(answers.verify(1, "abc", 1, "42", true), Verdict::Recorded);
+        assert_eq!(answers.verify(1, "abc", 1, "42", true), Verdict::Pass);
+        assert_eq!(
+            answers.verify(1, "abc", 1, "43", true),
+            Verdict::Fail {
+                expected: "42".to_string()
+            }
+        );
+        assert_eq!(answers.get(1, "abc", 2), None);
+        assert_eq!(answers.get(1, "abd", 1), None);
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut answers = Answers::default();
+        answers.verify(10, "abc", 1, "13140", true);
+        answers.verify(10, "abc", 2, "##..\n..##\n", true);
+        let content = toml::to_string_pretty(&answers).unwrap();
+        assert_eq!(toml::from_str::<Answers>(&content).unwrap(), answers);
+    }
+
+    #[test]
+    fn line_diff() {
+        assert_eq!(diff("1", "2"), "- 1\n+ 2\n");
+        assert_eq!(diff("#.\n..\n", "#.\n.#\n"), "  #.\n- ..\n+ .#\n");
+        assert_eq!(diff("a\nb", "a"), "  a\n- b\n");
+    }
+}
@@ -1,129 +1,886 @@
-use advent_of_code::{days::*, solver::Solver};
-use std::{fmt::Debug, time::Instant};
-
-const YEAR: &str = "2022";
+use advent_of_code::{
+    answers::{diff, Answers},
+    error::Result,
+    fetch::{self, Fetcher, Http},
+    history::{compare, git_dirty, git_rev, today, Entry, History},
+    memory::{self, Counting, Usage},
+    registry::{Registry, DEFAULT_VARIANT},
+    runner::{
+        cross_check, input_path, parallel_map, read_input, solve_day, verify, DayRange, Record,
+        Status,
+    },
+    scaffold,
+    solver::DynSolver,
+    submit::{self, Attempts, Outcome},
+    visualize::{Backend, Frames, ImageFormat, Images, Terminal},
+    years,
+};
+use clap::{
+    builder::{PossibleValuesParser, TypedValueParser},
+    Args, Parser, Subcommand, ValueEnum,
+};
+use std::{
+    collections::BTreeMap,
+    num::NonZeroUsize,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    sync::LazyLock,
+    time::{Duration, Instant},
+};
 
 #[cfg(debug_assertions)]
 #[global_allocator]
-static ALLOCATOR: dhat::Alloc = dhat::Alloc;
+static ALLOCATOR: Counting<dhat::Alloc> = Counting(dhat::Alloc);
+
+#[cfg(not(debug_assertions))]
+#[global_allocator]
+static ALLOCATOR: Counting<std::alloc::System> = Counting(std::alloc::System);
+
+const CRITERION_DIR: &str = "target/criterion";
+
+static FETCHER: LazyLock<Fetcher> =
+    LazyLock::new(|| Fetcher::new(fetch::session(), Box::new(Http)));
+
+/// Runs, benchmarks and checks Advent of Code solutions.
+#[derive(Parser)]
+#[command(version)]
+struct Cli {
+    /// Event year to use, the latest available one by default
+    #[arg(long, global = true)]
+    year: Option<u16>,
+
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve days and print their answers and timings (the default)
+    Run(Solve),
+    /// Benchmark days with criterion
+    Bench(Bench),
+    /// Solve days and compare their answers with the ones in answers/<year>.toml
+    Check(Solve),
+    /// List the available days
+    List,
+    /// Create a new day from the template, with an empty input and example
+    NewDay {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+    /// Solve a part and submit its answer, unless earlier attempts already tell the outcome
+    Submit {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+    },
+    /// Show how a day solves a part, animated in the terminal or written as numbered images
+    Visualize(Visualize),
+}
+
+#[derive(Args, Default)]
+struct Solve {
+    #[command(flatten)]
+    selection: Selection,
+
+    /// Store the computed answers of parts that have no known answer yet
+    #[arg(long)]
+    record: bool,
+
+    /// Report format; `json` and `csv` print one record per day and part
+    #[arg(long, value_enum, default_value_t)]
+    format: Format,
+
+    /// Solve days in parallel on this many threads, 0 for one per CPU
+    #[arg(long, value_name = "N")]
+    jobs: Option<usize>,
+
+    /// Count the heap allocations of each parser and part, and report them after the summary
+    #[arg(long)]
+    mem: bool,
+}
+
+#[derive(Args)]
+struct Bench {
+    #[command(flatten)]
+    selection: Selection,
+
+    /// Compare with the latest results saved for this git revision
+    #[arg(long, value_name = "REV")]
+    compare: Option<String>,
+
+    /// Slowdown in percent above which --compare fails
+    #[arg(long, value_name = "PERCENT", default_value_t = 10.0)]
+    threshold: f64,
+}
+
+#[derive(Args)]
+struct Visualize {
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+
+    /// Write the frames as images in this directory instead of animating them in the terminal
+    #[arg(long, value_name = "DIR")]
+    out: Option<PathBuf>,
+
+    /// Image format of the frames written with --out
+    #[arg(long, default_value = "png", value_parser = PossibleValuesParser::new(["ppm", "png"])
+        .map(|format| if format == "ppm" { ImageFormat::Ppm } else { ImageFormat::Png }))]
+    format: ImageFormat,
+
+    /// Size in pixels of a cell in the images
+    #[arg(long, value_name = "N", default_value_t = 4)]
+    scale: usize,
+
+    /// Only draw one step out of every N
+    #[arg(long, value_name = "N", default_value_t = 1)]
+    every: usize,
+
+    /// Pause between frames in the terminal, in milliseconds
+    #[arg(long, value_name = "MS", default_value_t = 50)]
+    delay: u64,
+
+    /// Read the input from this file instead of input/<year>/day<N>.txt, or from stdin with `-`
+    #[arg(long)]
+    input: Option<PathBuf>,
+}
+
+#[derive(Args, Default)]
+struct Selection {
+    /// Days to select, like `5`, `1-5` or `1,3,7-9`; all days by default
+    #[arg(value_delimiter = ',')]
+    days: Vec<DayRange>,
+
+    /// Only solve the given part
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// Read the input from this file instead of input/<year>/day<N>.txt, or from stdin with `-`
+    #[arg(long)]
+    input: Option<PathBuf>,
+
+    /// Solve with this variant of the days instead of their default implementation, skipping the
+    /// days without it; `bench` compares it with the default one
+    #[arg(long, value_name = "NAME")]
+    variant: Option<String>,
+}
+
+impl Selection {
+    fn runs(&self, part: u8) -> bool {
+        self.part.is_none_or(|p| p == part)
+    }
+
+    fn variant(&self) -> &str {
+        self.variant.as_deref().unwrap_or(DEFAULT_VARIANT)
+    }
+
+    fn parts(&self) -> Vec<u8> {
+        [1, 2].into_iter().filter(|&part| self.runs(part)).collect()
+    }
+}
 
-macro_rules! day {
-    ( $d:expr ) => {
-        day!($d => None, None);
+#[derive(Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+enum Format {
+    #[default]
+    Text,
+    Json,
+    Csv,
+}
+
+fn main() -> ExitCode {
+    #[cfg(debug_assertions)]
+    let _dhat = dhat::Profiler::new_heap();
+
+    let cli = Cli::parse();
+    let registry = years::registry();
+    if let Some(Command::NewDay { day }) = cli.command {
+        return new_day(&registry, cli.year, day);
+    }
+    let Some(year) = cli.year.or_else(|| registry.years().last()) else {
+        eprintln!("No days are registered");
+        return ExitCode::FAILURE;
     };
+    if !registry.years().any(|x| x == year) {
+        eprintln!(
+            "No days for {}, available years: {:?}",
+            year,
+            registry.years().collect::<Vec<_>>()
+        );
+        return ExitCode::FAILURE;
+    }
 
-    ( $d:expr, $o1:expr ) => {
-        day!($d => Some($o1), None);
+    let success = match cli
+        .command
+        .unwrap_or_else(|| Command::Run(Solve::default()))
+    {
+        Command::Run(args) => solve(&registry, year, &args, false),
+        Command::Bench(args) => bench(&registry, year, &args),
+        Command::Check(args) => solve(&registry, year, &args, true),
+        Command::List => {
+            list(&registry, cli.year);
+            true
+        }
+        Command::Submit { day, part } => submit(&registry, year, day, part),
+        Command::Visualize(args) => visualize(&registry, year, &args),
+        Command::NewDay { .. } => unreachable!(),
     };
+    if success {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
 
-    ( $d:expr, $o1:expr, $o2:expr ) => {
-        day!($d => Some($o1), Some($o2));
+fn new_day(registry: &Registry, year: Option<u16>, day: u8) -> ExitCode {
+    let Some(year) = year.or_else(|| registry.years().last()) else {
+        eprintln!("No days are registered yet, pick a year with --year");
+        return ExitCode::FAILURE;
     };
+    match scaffold::new_day(Path::new("."), year, day) {
+        Ok(created) => {
+            for path in created {
+                println!("Created {}", path.display());
+            }
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("Could not create day {} of {}: {}", day, year, err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn list(registry: &Registry, year: Option<u16>) {
+    for y in registry
+        .years()
+        .filter(|&y| year.is_none_or(|year| year == y))
+    {
+        let days = registry
+            .days()
+            .filter(|&(year, _)| year == y)
+            .map(|(_, day)| day.to_string())
+            .collect::<Vec<_>>();
+        println!("{}: {}", y, days.join(" "));
+        for (_, day) in registry.days().filter(|&(year, _)| year == y) {
+            let variants = registry
+                .variants(y, day)
+                .skip(1)
+                .map(|(name, _)| name)
+                .collect::<Vec<_>>();
+            if !variants.is_empty() {
+                println!("  day {} variants: {}", day, variants.join(", "));
+            }
+        }
+    }
+}
 
-    ( $d:expr => $o1:expr, $o2:expr ) => {
-        paste::expr! {
-            solve::<_, _, [<day $d>]::[<Day $d>]>($d, $o1, $o2);
+fn select<'a>(
+    registry: &'a Registry,
+    year: u16,
+    selection: &Selection,
+) -> Option<Vec<(u8, &'a dyn DynSolver)>> {
+    let days = registry
+        .iter()
+        .filter(|&((y, day), _)| {
+            y == year
+                && (selection.days.is_empty()
+                    || selection.days.iter().any(|range| range.contains(day)))
+        })
+        .filter_map(|((_, day), _)| {
+            Some((day, registry.get_variant(year, day, selection.variant())?))
+        })
+        .collect::<Vec<_>>();
+    if days.is_empty() {
+        match &selection.variant {
+            Some(variant) => {
+                eprintln!("No matching days for {} with a `{}` variant", year, variant)
+            }
+            None => eprintln!("No matching days for {}", year),
         }
+        return None;
+    }
+    if selection.input.is_some() && days.len() > 1 {
+        eprintln!("--input needs exactly one day, {} are selected", days.len());
+        return None;
+    }
+    Some(days)
+}
+
+/// Reads the input given with --input, or else the one of the day, downloading it if missing.
+fn load_input(year: u16, day: u8, selection: &Selection) -> Result<Vec<u8>, String> {
+    match &selection.input {
+        Some(path) => {
+            read_input(path).map_err(|err| format!("could not read {}: {}", path.display(), err))
+        }
+        None => FETCHER
+            .input(year, day)
+            .map_err(|err| format!("could not get {}: {}", input_path(year, day).display(), err)),
+    }
+}
+
+fn solve(registry: &Registry, year: u16, args: &Solve, check: bool) -> bool {
+    let Some(days) = select(registry, year, &args.selection) else {
+        return false;
+    };
+    let answers_path = Answers::path(year);
+    let mut answers = match Answers::load(&answers_path) {
+        Ok(answers) => answers,
+        Err(err) => {
+            eprintln!("Could not load {}: {}", answers_path.display(), err);
+            return false;
+        }
+    };
+
+    if args.format == Format::Text {
+        println!("AOC {}", year);
+    }
+    if args.mem {
+        memory::enable();
+    }
+    let parts = args.selection.parts();
+    let solve_one =
+        |&(day, solver): &(u8, &dyn DynSolver)| match load_input(year, day, &args.selection) {
+            Ok(input) => {
+                let mut records = solve_day(year, day, solver, &input, &parts);
+                if check {
+                    let others = registry
+                        .variants(year, day)
+                        .filter(|&(name, _)| name != args.selection.variant())
+                        .collect::<Vec<_>>();
+                    cross_check(&mut records, &others, &input);
+                }
+                records
+            }
+            Err(err) => parts
+                .iter()
+                .map(|&part| Record::failure(year, day, part, err.clone()))
+                .collect(),
+        };
+
+    let start_time = Instant::now();
+    let solved: Box<dyn Iterator<Item = Vec<Record>>> = match args.jobs {
+        Some(jobs) => {
+            let jobs = if jobs == 0 {
+                std::thread::available_parallelism().map_or(1, NonZeroUsize::get)
+            } else {
+                jobs
+            };
+            Box::new(parallel_map(&days, jobs, solve_one).into_iter())
+        }
+        None => Box::new(days.iter().map(solve_one)),
     };
+    let mut records = Vec::new();
+    for mut day_records in solved {
+        verify(&mut day_records, &mut answers, args.record);
+        if args.format == Format::Text {
+            if check {
+                print_check(&day_records);
+            } else {
+                print_run(&day_records);
+            }
+        }
+        records.extend(day_records);
+    }
+    let wall_time = start_time.elapsed();
+
+    let mut success = records.iter().all(|record| match record.status {
+        Status::Pass | Status::Recorded => true,
+        Status::Unchecked => !check,
+        Status::Fail | Status::Error | Status::Panic => false,
+    });
+    match args.format {
+        Format::Text => {
+            print_summary(&records, wall_time);
+            if args.mem {
+                print_memory(&records);
+            }
+        }
+        Format::Json => println!("{}", serde_json::to_string_pretty(&records).unwrap()),
+        Format::Csv => {
+            let mut writer = csv::Writer::from_writer(std::io::stdout());
+            for record in &records {
+                writer.serialize(record).unwrap();
+            }
+            writer.flush().unwrap();
+        }
+    }
+
+    let recorded = records
+        .iter()
+        .filter(|record| record.status == Status::Recorded)
+        .count();
+    if recorded > 0 {
+        match answers.save(&answers_path) {
+            Ok(()) => eprintln!(
+                "\nRecorded {} answers in {}",
+                recorded,
+                answers_path.display()
+            ),
+            Err(err) => {
+                eprintln!("Could not save {}: {}", answers_path.display(), err);
+                success = false;
+            }
+        }
+    }
+    success
 }
 
-fn main() {
-    #[cfg(debug_assertions)]
-    let _dhat = dhat::Profiler::new_heap();
+fn visualize(registry: &Registry, year: u16, args: &Visualize) -> bool {
+    let Some(visualizer) = registry.visualizer(year, args.day) else {
+        eprintln!("Day {} of {} has no visualization", args.day, year);
+        return false;
+    };
+    let selection = Selection {
+        input: args.input.clone(),
+        ..Selection::default()
+    };
+    let input = match load_input(year, args.day, &selection) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("Day {}: {}", args.day, err);
+            return false;
+        }
+    };
+    let mut backend: Box<dyn Backend> = match &args.out {
+        Some(dir) => Box::new(Images::new(dir.clone(), args.format, args.scale)),
+        None => Box::new(Terminal {
+            out: std::io::stdout().lock(),
+            delay: Duration::from_millis(args.delay),
+        }),
+    };
+    let mut frames = Frames::new(backend.as_mut(), args.every);
+    if let Err(err) = visualizer.visualize(&input, args.part, &mut frames) {
+        eprintln!("Day {} part {}: {}", args.day, args.part, err);
+        return false;
+    }
+    match frames.finish() {
+        Ok(count) => {
+            if let Some(dir) = &args.out {
+                println!("Wrote {} frames to {}", count, dir.display());
+            }
+            true
+        }
+        Err(err) => {
+            eprintln!("Could not draw a frame: {}", err);
+            false
+        }
+    }
+}
 
-    println!("AOC {}", YEAR);
-    day!(1, 68292, 203203);
-    day!(2, 11063, 10349);
-    day!(3, 7831, 2683);
-    day!(4, 542, 900);
-    day!(5, "QGTHFZBHV".to_string(), "MGDMPSZTM".to_string());
-    day!(6, 1343, 2193);
-    day!(7, 1325919, 2050735);
-    day!(8, 1703, 496650);
-    day!(9, 6498, 2531);
-    day!(10, 13920, "####..##..#....#..#.###..#....####...##.\n#....#..#.#....#..#.#..#.#....#.......#.\n###..#....#....####.###..#....###.....#.\n#....#.##.#....#..#.#..#.#....#.......#.\n#....#..#.#....#..#.#..#.#....#....#..#.\n####..###.####.#..#.###..####.#.....##..\n".to_string());
-    day!(11, 120056, 21816744824);
-    day!(12, 504, 500);
-    day!(13, 5506, 21756);
-    day!(14, 1003, 25771);
-    day!(15, 5511201, 11318723411840);
-}
-
-fn solve<O, O2, S: for<'a> Solver<'a, Output = O, Output2 = O2>>(
-    day_number: u8,
-    part1_output: Option<O>,
-    part2_output: Option<O2>,
-) {
-    let input = std::fs::read_to_string(format!("input/{}/day{}.txt", YEAR, day_number)).unwrap();
-    let trimmed = input.as_str();
-
-    let mut args = std::env::args();
-    let day_as_str = day_number.to_string();
-    if args.len() > 2 {
-        if args.any(|x| x == day_as_str || x == "a") {
-            bench::<S>(day_number, trimmed);
+fn submit(registry: &Registry, year: u16, day: u8, part: u8) -> bool {
+    let Some(solver) = registry.get(year, day) else {
+        eprintln!("No day {} for {}", day, year);
+        return false;
+    };
+    let input = match load_input(year, day, &Selection::default()) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("Day {}: {}", day, err);
+            return false;
         }
+    };
+    let mut records = solve_day(year, day, solver, &input, &[part]);
+    let record = &mut records[0];
+    let Some(answer) = record.answer.clone().filter(|answer| !answer.is_empty()) else {
+        eprintln!(
+            "Day {} part {}: no answer to submit, {}",
+            day,
+            part,
+            record.error.as_deref().unwrap_or("it is empty")
+        );
+        return false;
+    };
+    println!("Day {} part {}: submitting {}", day, part, answer);
+
+    let submission = match submit::submit(
+        &FETCHER,
+        Path::new(Attempts::PATH),
+        year,
+        day,
+        part,
+        &answer,
+    ) {
+        Ok(submission) => submission,
+        Err(err) => {
+            eprintln!("Could not submit: {}", err);
+            return false;
+        }
+    };
+    if submission.known {
+        println!(
+            "Not sent: {} (inferred from earlier attempts)",
+            submission.outcome
+        );
     } else {
-        if args.any(|x| x == day_as_str) || args.len() <= 1 {
-            run::<S>(day_number, trimmed, part1_output, part2_output);
+        println!("That's the {}", submission.outcome);
+    }
+    if !submission.wait.is_zero() {
+        println!("Wait {:?} before submitting again", submission.wait);
+    }
+    if submission.outcome != Outcome::Correct {
+        return false;
+    }
+
+    let answers_path = Answers::path(year);
+    let mut answers = match Answers::load(&answers_path) {
+        Ok(answers) => answers,
+        Err(err) => {
+            eprintln!("Could not load {}: {}", answers_path.display(), err);
+            return false;
         }
+    };
+    verify(&mut records, &mut answers, true);
+    match records[0].status {
+        Status::Recorded => match answers.save(&answers_path) {
+            Ok(()) => println!("Recorded the answer in {}", answers_path.display()),
+            Err(err) => {
+                eprintln!("Could not save {}: {}", answers_path.display(), err);
+                return false;
+            }
+        },
+        Status::Fail => println!(
+            "It differs from the answer in {}!\n{}",
+            answers_path.display(),
+            record_diff(&records[0])
+        ),
+        _ => {}
     }
+    true
 }
 
-fn run<'a, S: Solver<'a>>(
-    day_number: u8,
-    input: &'a str,
-    part1_output: Option<S::Output>,
-    part2_output: Option<S::Output2>,
-) {
-    let start_time = Instant::now();
-    let parsed = S::parse(input);
-    let end_time = Instant::now();
+fn print_run(records: &[Record]) {
+    let Some(first) = records.first() else {
+        return;
+    };
+    // Unreadable or unparsable inputs fail every part the same way.
+    if first.answer.is_none() && records.iter().all(|record| record.error == first.error) {
+        if let Some(err) = &first.error {
+            eprintln!("\nDay {}: {}", first.day, err);
+            return;
+        }
+    }
 
-    println!("\nDay {}:", day_number);
-    println!("\tparser: {:?}", (end_time - start_time));
+    println!("\nDay {}:", first.day);
+    println!("\tparser: {:?}", first.parse_time);
+    for record in records {
+        match (&record.answer, &record.error) {
+            (Some(answer), _) => println!("Part {}: {}", record.part, answer),
+            (None, error) => println!(
+                "Part {}: {}",
+                record.part,
+                error.as_deref().unwrap_or_default()
+            ),
+        }
+        println!("\tsolver: {:?}", record.solve_time);
+        match record.status {
+            Status::Fail => print!("Wrong answer!\n{}", record_diff(record)),
+            Status::Unchecked => println!("Not checking result!"),
+            Status::Recorded => println!("Recorded result!"),
+            Status::Pass | Status::Error | Status::Panic => {}
+        }
+    }
+    if let Some(both_time) = first.both_time {
+        println!("Both parts at once:\n\tsolver: {:?}", both_time);
+    }
+}
 
-    run_part(parsed.clone(), 1, S::part1, part1_output);
-    run_part(parsed, 2, S::part2, part2_output);
+fn print_check(records: &[Record]) {
+    for record in records {
+        let status = match record.status {
+            Status::Pass => "ok".to_string(),
+            Status::Fail => format!("FAILED\n{}", record_diff(record)),
+            Status::Unchecked => "unchecked".to_string(),
+            Status::Recorded => "recorded".to_string(),
+            Status::Error => format!("error: {}", record.error.as_deref().unwrap_or_default()),
+            Status::Panic => record.error.clone().unwrap_or_default(),
+        };
+        println!(
+            "Day {} part {}: {}",
+            record.day,
+            record.part,
+            status.trim_end()
+        );
+    }
 }
 
-fn run_part<P, O: Debug + PartialEq>(
-    parsed: P,
-    part_number: u8,
-    part: impl Fn(P) -> O,
-    expected_output: Option<O>,
-) {
-    print!("Part {}: ", part_number);
+fn print_summary(records: &[Record], wall_time: Duration) {
+    let days = records
+        .chunk_by(|a, b| a.day == b.day)
+        .map(|day| {
+            let time = day[0].parse_time + day.iter().map(|record| record.solve_time).sum();
+            (day, time)
+        })
+        .collect::<Vec<_>>();
+    let total = days.iter().map(|&(_, time)| time).sum::<Duration>();
+    let share = |time: Duration| time.as_secs_f64() / total.as_secs_f64().max(f64::EPSILON) * 100.0;
 
-    let start_time = Instant::now();
-    let result = part(parsed);
-    let end_time = Instant::now();
+    println!("\nSummary:");
+    println!("Day  Part 1     Part 2     {:>12}  Share", "Time");
+    for &(day, time) in &days {
+        let status = |part| {
+            day.iter()
+                .find(|record| record.part == part)
+                .map_or_else(|| "-".to_string(), |record| record.status.to_string())
+        };
+        println!(
+            "{:>3}  {:<10} {:<10} {:>12}  {:>4.1}%",
+            day[0].day,
+            status(1),
+            status(2),
+            format!("{:?}", time),
+            share(time)
+        );
+    }
 
-    println!("{:?}", result);
-    println!("\tsolver: {:?}", (end_time - start_time));
+    let mut counts = BTreeMap::new();
+    for record in records {
+        *counts.entry(record.status.to_string()).or_insert(0) += 1;
+    }
+    let counts = counts
+        .iter()
+        .map(|(status, count)| format!("{} {}", count, status))
+        .collect::<Vec<_>>();
+    println!("{}", counts.join(", "));
 
-    if let Some(expected) = expected_output {
-        assert_eq!(expected, result);
-    } else {
-        println!("Not checking result!");
+    let mut slowest = days.clone();
+    slowest.sort_by_key(|&(_, time)| std::cmp::Reverse(time));
+    let slowest = slowest
+        .iter()
+        .take(3)
+        .map(|&(day, time)| format!("day {} ({:.1}%)", day[0].day, share(time)))
+        .collect::<Vec<_>>();
+    println!(
+        "Total: {:?} over all days, {:?} wall time",
+        total, wall_time
+    );
+    println!("Slowest: {}", slowest.join(", "));
+}
+
+fn print_memory(records: &[Record]) {
+    println!("\nMemory:");
+    println!(
+        "Day  Step    {:>11}  {:>10}  {:>10}",
+        "Allocations", "Allocated", "Peak"
+    );
+    let row = |day: u8, step: &str, usage: Option<Usage>| {
+        if let Some(usage) = usage {
+            println!(
+                "{:>3}  {:<6}  {:>11}  {:>10}  {:>10}",
+                day,
+                step,
+                usage.allocations,
+                bytes(usage.bytes),
+                bytes(usage.peak)
+            );
+        }
+    };
+    for day in records.chunk_by(|a, b| a.day == b.day) {
+        row(day[0].day, "parse", day[0].parse_memory);
+        for record in day {
+            row(
+                record.day,
+                &format!("part {}", record.part),
+                record.solve_memory,
+            );
+        }
     }
 }
 
-fn bench<'a, S: Solver<'a>>(day_number: u8, input: &'a str) {
-    let mut criterion = criterion::Criterion::default().without_plots();
-    let mut group = criterion.benchmark_group(format!("Day {}", day_number));
+#[allow(clippy::cast_precision_loss)]
+fn bytes(bytes: u64) -> String {
+    match bytes {
+        0..1024 => format!("{} B", bytes),
+        1024..1_048_576 => format!("{:.1} KiB", bytes as f64 / 1024.0),
+        _ => format!("{:.1} MiB", bytes as f64 / 1_048_576.0),
+    }
+}
 
-    group.bench_with_input("parser", &input, |b, i| {
-        b.iter_with_large_drop(|| S::parse(i));
-    });
+fn record_diff(record: &Record) -> String {
+    diff(
+        record.expected.as_deref().unwrap_or_default(),
+        record.answer.as_deref().unwrap_or_default(),
+    )
+}
 
-    let parsed = S::parse(input);
+fn bench(registry: &Registry, year: u16, args: &Bench) -> bool {
+    let Some(days) = select(registry, year, &args.selection) else {
+        return false;
+    };
+    let baseline = match &args.compare {
+        Some(rev) => match (git_rev(rev), History::load(Path::new(History::PATH))) {
+            (Some(rev), Ok(history)) => Some((rev, history)),
+            (None, _) => {
+                eprintln!("Unknown git revision {}", rev);
+                return false;
+            }
+            (_, Err(err)) => {
+                eprintln!("Could not load {}: {}", History::PATH, err);
+                return false;
+            }
+        },
+        None => None,
+    };
 
-    group.bench_with_input("part 1", &parsed, |b, i| {
-        b.iter_batched(|| i.clone(), S::part1, criterion::BatchSize::SmallInput)
-    });
+    let mut criterion = criterion::Criterion::default()
+        .without_plots()
+        .output_directory(Path::new(CRITERION_DIR));
+    let mut success = true;
+    let mut entries = Vec::new();
+    for (day, _) in days {
+        // The default implementation is measured and saved, the variants are measured next to it.
+        let mut variants = registry.variants(year, day).filter(|&(name, _)| {
+            args.selection
+                .variant
+                .as_ref()
+                .is_none_or(|variant| variant == name)
+                || name == DEFAULT_VARIANT
+        });
+        let Some((_, solver)) = variants.next() else {
+            continue;
+        };
+        let variants = variants.collect::<Vec<_>>();
+        let result = load_input(year, day, &args.selection).and_then(|input| {
+            bench_day(
+                &mut criterion,
+                year,
+                day,
+                solver,
+                &variants,
+                &input,
+                &args.selection,
+            )
+            .map_err(|err| err.to_string())
+        });
+        match result {
+            Ok(entry) => entries.extend(entry),
+            Err(err) => {
+                eprintln!("\nDay {}: {}", day, err);
+                success = false;
+            }
+        }
+    }
 
-    group.bench_with_input("part 2", &parsed, |b, i| {
-        b.iter_batched(|| i.clone(), S::part2, criterion::BatchSize::SmallInput)
-    });
+    match git_rev("HEAD") {
+        Some(rev) => {
+            let dirty = git_dirty();
+            for entry in &mut entries {
+                entry.rev.clone_from(&rev);
+                entry.dirty = dirty;
+            }
+            if let Err(err) = History::append(Path::new(History::PATH), &entries) {
+                eprintln!("Could not save {}: {}", History::PATH, err);
+                success = false;
+            }
+        }
+        None => eprintln!("Not in a git checkout, the results are not saved"),
+    }
+
+    if let Some((rev, history)) = baseline {
+        println!("\nCompared to {}:", &rev[..rev.len().min(12)]);
+        for entry in &entries {
+            let Some(before) = history.latest(&rev, entry.year, entry.day) else {
+                println!("{} Day {}: no results", entry.year, entry.day);
+                continue;
+            };
+            for change in compare(before, entry) {
+                if change.regressed(args.threshold) {
+                    println!("{} REGRESSION", change);
+                    success = false;
+                } else {
+                    println!("{}", change);
+                }
+            }
+        }
+    }
+    success
+}
+
+/// Mean time in nanoseconds that criterion measured for a benchmark of its last run.
+fn estimate(group: &str, id: &str) -> Option<f64> {
+    let path = Path::new(CRITERION_DIR)
+        .join(group)
+        .join(id)
+        .join("new/estimates.json");
+    let estimates: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(path).ok()?).ok()?;
+    estimates["mean"]["point_estimate"].as_f64()
+}
+
+fn bench_day(
+    criterion: &mut criterion::Criterion,
+    year: u16,
+    day: u8,
+    solver: &dyn DynSolver,
+    variants: &[(&str, &dyn DynSolver)],
+    input: &[u8],
+    selection: &Selection,
+) -> Result<Option<Entry>> {
+    let name = format!("{} Day {}", year, day);
+    let mut group = criterion.benchmark_group(&name);
+
+    // The text parser is measured on input already validated as UTF-8, next to the bytes parser
+    // for the days that have one.
+    let text = std::str::from_utf8(input).ok();
+    if let Some(text) = text {
+        group.bench_with_input("parser", &text, |b, i| {
+            b.iter_with_large_drop(|| solver.parse(i));
+        });
+    }
+    if solver.parses_bytes() {
+        group.bench_with_input("parser (bytes)", &input, |b, i| {
+            b.iter_with_large_drop(|| solver.parse_bytes(i));
+        });
+    }
+
+    let parsed = solver.parse_bytes(input)?;
+
+    if selection.runs(1) {
+        group.bench_function("part 1", |b| b.iter(|| parsed.part1()));
+    }
+
+    if selection.runs(2) {
+        group.bench_function("part 2", |b| b.iter(|| parsed.part2()));
+    }
+
+    let both = selection.part.is_none() && parsed.solve_both().is_some();
+    if both {
+        group.bench_function("both parts", |b| b.iter(|| parsed.solve_both()));
+    }
+
+    for (variant, solver) in variants {
+        let parsed = solver.parse_bytes(input)?;
+        if selection.runs(1) {
+            group.bench_function(format!("part 1 ({variant})"), |b| b.iter(|| parsed.part1()));
+        }
+        if selection.runs(2) {
+            group.bench_function(format!("part 2 ({variant})"), |b| b.iter(|| parsed.part2()));
+        }
+    }
+    group.finish();
+
+    Ok(text.and(estimate(&name, "parser")).map(|parser| Entry {
+        rev: String::new(),
+        dirty: false,
+        date: today(),
+        year,
+        day,
+        parser,
+        parser_bytes: solver
+            .parses_bytes()
+            .then(|| estimate(&name, "parser (bytes)"))
+            .flatten(),
+        part1: selection
+            .runs(1)
+            .then(|| estimate(&name, "part 1"))
+            .flatten(),
+        part2: selection
+            .runs(2)
+            .then(|| estimate(&name, "part 2"))
+            .flatten(),
+        both: both.then(|| estimate(&name, "both parts")).flatten(),
+    }))
 }
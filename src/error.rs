@@ -0,0 +1,118 @@
+use std::fmt::Display;
+
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error {
+    pub day: Option<u8>,
+    pub kind: ErrorKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ErrorKind {
+    Parse {
+        line: usize,
+        column: usize,
+        expected: String,
+    },
+    NoSolution,
+}
+
+impl Error {
+    /// Creates a parse error located at the start of `at`, which must be a slice of `input`.
+    /// Slices that don't point into `input` are reported at the end of the input.
+    pub fn parse(input: &str, at: &str, expected: impl Into<String>) -> Self {
+        Self::parse_bytes(input.as_bytes(), at.as_bytes(), expected)
+    }
+
+    /// Same as [`Error::parse`] for raw input bytes, counting columns in characters as far as the
+    /// bytes are valid UTF-8.
+    pub fn parse_bytes(input: &[u8], at: &[u8], expected: impl Into<String>) -> Self {
+        let offset = (at.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|&offset| offset <= input.len())
+            .unwrap_or(input.len());
+        let before = &input[..offset];
+        let line_start = before
+            .iter()
+            .rposition(|&byte| byte == b'\n')
+            .map_or(0, |i| i + 1);
+        Self {
+            day: None,
+            kind: ErrorKind::Parse {
+                line: before.split(|&byte| byte == b'\n').count(),
+                column: String::from_utf8_lossy(&before[line_start..])
+                    .chars()
+                    .count()
+                    + 1,
+                expected: expected.into(),
+            },
+        }
+    }
+
+    #[must_use]
+    pub const fn no_solution() -> Self {
+        Self {
+            day: None,
+            kind: ErrorKind::NoSolution,
+        }
+    }
+
+    #[must_use]
+    pub const fn with_day(mut self, day: u8) -> Self {
+        self.day = Some(day);
+        self
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(day) = self.day {
+            write!(f, "day {day}: ")?;
+        }
+        match &self.kind {
+            ErrorKind::Parse {
+                line,
+                column,
+                expected,
+            } => write!(f, "line {line}, column {column}: expected {expected}"),
+            ErrorKind::NoSolution => write!(f, "no solution found"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn position() {
+        let input = "abc\ndéf\nghi";
+        let err = Error::parse(input, &input[7..], "`f`");
+        assert_eq!(
+            err.kind,
+            ErrorKind::Parse {
+                line: 2,
+                column: 3,
+                expected: "`f`".to_string()
+            }
+        );
+        assert_eq!(err.to_string(), "line 2, column 3: expected `f`");
+    }
+
+    #[test]
+    fn position_outside_input() {
+        let input = "abc\nd";
+        let err = Error::parse(input, "elsewhere", "`e`").with_day(3);
+        assert_eq!(err.to_string(), "day 3: line 2, column 2: expected `e`");
+    }
+
+    #[test]
+    fn position_in_bytes() {
+        let input = b"ab\n\xffc";
+        let err = Error::parse_bytes(input, &input[4..], "`d`");
+        assert_eq!(err.to_string(), "line 2, column 2: expected `d`");
+    }
+}
@@ -0,0 +1,352 @@
+use std::{
+    io::{Error, ErrorKind, Read, Result},
+    path::PathBuf,
+    sync::{Mutex, PoisonError},
+    thread,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use serde::Deserialize;
+
+use crate::{runner::input_path, util::date::civil};
+
+pub const SESSION_VAR: &str = "AOC_SESSION";
+pub const CONFIG_PATH: &str = "aoc.toml";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+/// Sends the requests of a [`Fetcher`], so that tests can swap the network for something local.
+pub trait Transport: Send + Sync {
+    fn get(&self, url: &str, session: &str) -> Result<Response>;
+    fn post(&self, url: &str, session: &str, form: &[(&str, &str)]) -> Result<Response>;
+}
+
+/// Plain HTTP(S) with a blocking client.
+pub struct Http;
+
+const USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
+
+impl Http {
+    fn request(method: &str, url: &str, session: &str) -> ureq::Request {
+        ureq::request(method, url)
+            .set("Cookie", &format!("session={session}"))
+            .set("User-Agent", USER_AGENT)
+    }
+
+    fn read(response: std::result::Result<ureq::Response, ureq::Error>) -> Result<Response> {
+        let response = match response {
+            Ok(response) | Err(ureq::Error::Status(_, response)) => response,
+            Err(ureq::Error::Transport(err)) => return Err(Error::other(err)),
+        };
+        let status = response.status();
+        let mut body = String::new();
+        response.into_reader().read_to_string(&mut body)?;
+        Ok(Response { status, body })
+    }
+}
+
+impl Transport for Http {
+    fn get(&self, url: &str, session: &str) -> Result<Response> {
+        Self::read(Self::request("GET", url, session).call())
+    }
+
+    fn post(&self, url: &str, session: &str, form: &[(&str, &str)]) -> Result<Response> {
+        Self::read(Self::request("POST", url, session).send_form(form))
+    }
+}
+
+#[derive(Deserialize)]
+struct Config {
+    session: Option<String>,
+}
+
+/// The session token from the `AOC_SESSION` env var, or else from the `session` key of aoc.toml.
+#[must_use]
+pub fn session() -> Option<String> {
+    std::env::var(SESSION_VAR)
+        .ok()
+        .or_else(|| {
+            let config = std::fs::read_to_string(CONFIG_PATH).ok()?;
+            toml::from_str::<Config>(&config).ok()?.session
+        })
+        .map(|session| session.trim().to_string())
+        .filter(|session| !session.is_empty())
+}
+
+/// Downloads puzzle inputs into input/<year>/day<N>.txt, at most one request per `interval`.
+pub struct Fetcher {
+    pub base_url: String,
+    pub root: PathBuf,
+    pub interval: Duration,
+    session: Option<String>,
+    transport: Box<dyn Transport>,
+    last_request: Mutex<Option<Instant>>,
+}
+
+impl Fetcher {
+    pub const BASE_URL: &'static str = "https://adventofcode.com";
+
+    #[must_use]
+    pub fn new(session: Option<String>, transport: Box<dyn Transport>) -> Self {
+        Self {
+            base_url: Self::BASE_URL.to_string(),
+            root: PathBuf::from("."),
+            interval: Duration::from_secs(5),
+            session,
+            transport,
+            last_request: Mutex::new(None),
+        }
+    }
+
+    /// The input of a day, read from the cache or else downloaded into it. An empty file, like the
+    /// one `new-day` creates, counts as missing.
+    pub fn input(&self, year: u16, day: u8) -> Result<Vec<u8>> {
+        let path = self.root.join(input_path(year, day));
+        match std::fs::read(&path) {
+            Err(e) if e.kind() == ErrorKind::NotFound => {}
+            Ok(input) if input.is_empty() => {}
+            result => return result,
+        }
+
+        let response = self.get(year, &format!("/{year}/day/{day}/input"))?;
+        let input = match response.status {
+            200 => response.body.into_bytes(),
+            404 => {
+                return Err(Error::new(
+                    ErrorKind::NotFound,
+                    format!("no input for day {day} of {year}"),
+                ))
+            }
+            _ => return Err(status_error(&response)),
+        };
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(&path, &input)?;
+        Ok(input)
+    }
+
+    fn get(&self, year: u16, path: &str) -> Result<Response> {
+        let session = self.session(year)?;
+        self.throttle();
+        self.transport
+            .get(&format!("{}{path}", self.base_url), session)
+    }
+
+    pub(crate) fn post(&self, year: u16, path: &str, form: &[(&str, &str)]) -> Result<Response> {
+        let session = self.session(year)?;
+        self.throttle();
+        self.transport
+            .post(&format!("{}{path}", self.base_url), session, form)
+    }
+
+    fn session(&self, year: u16) -> Result<&str> {
+        if year_unlocked(year, SystemTime::now()).is_none_or(|unlocked| !unlocked) {
+            return Err(Error::new(
+                ErrorKind::NotFound,
+                format!("the {year} event hasn't started"),
+            ));
+        }
+        self.session.as_deref().ok_or_else(|| {
+            Error::new(
+                ErrorKind::PermissionDenied,
+                format!("no session token, set {SESSION_VAR} or `session` in {CONFIG_PATH}"),
+            )
+        })
+    }
+
+    /// Waits until `interval` has passed since the previous request.
+    fn throttle(&self) {
+        let mut last_request = self
+            .last_request
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        if let Some(wait) = last_request.and_then(|last| self.interval.checked_sub(last.elapsed()))
+        {
+            thread::sleep(wait);
+        }
+        *last_request = Some(Instant::now());
+    }
+}
+
+pub(crate) fn status_error(response: &Response) -> Error {
+    match response.status {
+        400 | 401 | 403 | 500 => Error::new(
+            ErrorKind::PermissionDenied,
+            format!("the session token was rejected ({})", response.status),
+        ),
+        429 => Error::other("rate limited by the server, try again later"),
+        status => Error::other(format!("unexpected HTTP status {status}")),
+    }
+}
+
+/// Whether the first puzzle of `year` is out at `now`, at midnight EST on December 1st.
+fn year_unlocked(year: u16, now: SystemTime) -> Option<bool> {
+    // Midnight EST is 5 am UTC.
+    let secs = now.duration_since(UNIX_EPOCH).ok()?.as_secs();
+    let (current, month, _) = civil(secs.checked_sub(5 * 3600)?);
+    Some((current, month) >= (i64::from(year), 12))
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::{
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+        thread::JoinHandle,
+    };
+
+    use super::*;
+
+    /// A stand-in for the puzzle server, answering with `respond(method, url, body)` to requests
+    /// that carry the `secret` session cookie and with a 400 to the others.
+    pub struct Server {
+        pub url: String,
+        pub requests: Arc<AtomicUsize>,
+        http: Arc<tiny_http::Server>,
+        thread: Option<JoinHandle<()>>,
+    }
+
+    impl Server {
+        pub fn start(respond: impl Fn(&str, &str, &str) -> (u16, String) + Send + 'static) -> Self {
+            let server = Arc::new(tiny_http::Server::http("127.0.0.1:0").unwrap());
+            let url = format!("http://{}", server.server_addr().to_ip().unwrap());
+            let requests = Arc::new(AtomicUsize::new(0));
+            let thread = thread::spawn({
+                let server = Arc::clone(&server);
+                let requests = Arc::clone(&requests);
+                move || {
+                    for mut request in server.incoming_requests() {
+                        requests.fetch_add(1, Ordering::SeqCst);
+                        let mut body = String::new();
+                        request.as_reader().read_to_string(&mut body).unwrap();
+                        let authorized = request.headers().iter().any(|header| {
+                            header.field.equiv("Cookie") && header.value == "session=secret"
+                        });
+                        let (status, body) = if authorized {
+                            respond(request.method().as_str(), request.url(), &body)
+                        } else {
+                            (400, "Please log in".to_string())
+                        };
+                        let response =
+                            tiny_http::Response::from_string(body).with_status_code(status);
+                        request.respond(response).unwrap();
+                    }
+                }
+            });
+            Self {
+                url,
+                requests,
+                http: server,
+                thread: Some(thread),
+            }
+        }
+
+        pub fn fetcher(&self, session: &str, root: &std::path::Path) -> Fetcher {
+            let mut fetcher = Fetcher::new(Some(session.to_string()), Box::new(Http));
+            fetcher.base_url.clone_from(&self.url);
+            fetcher.root = root.to_path_buf();
+            fetcher.interval = Duration::ZERO;
+            fetcher
+        }
+    }
+
+    impl Drop for Server {
+        fn drop(&mut self) {
+            self.http.unblock();
+            if let Some(thread) = self.thread.take() {
+                thread.join().unwrap();
+            }
+        }
+    }
+
+    fn inputs(method: &str, url: &str, _: &str) -> (u16, String) {
+        match (method, url) {
+            ("GET", "/2022/day/1/input") => (200, "1000\n2000\n".to_string()),
+            ("GET", "/2022/day/3/input") => (429, String::new()),
+            _ => (404, "Not Found".to_string()),
+        }
+    }
+
+    #[test]
+    fn fetch_and_cache() {
+        let root = tempfile::tempdir().unwrap();
+        let server = Server::start(inputs);
+        let fetcher = server.fetcher("secret", root.path());
+        std::fs::create_dir_all(root.path().join("input/2022")).unwrap();
+        std::fs::write(root.path().join("input/2022/day1.txt"), "").unwrap();
+
+        assert_eq!(fetcher.input(2022, 1).unwrap(), b"1000\n2000\n");
+        assert_eq!(
+            std::fs::read_to_string(root.path().join("input/2022/day1.txt")).unwrap(),
+            "1000\n2000\n"
+        );
+        assert_eq!(fetcher.input(2022, 1).unwrap(), b"1000\n2000\n");
+        assert_eq!(server.requests.load(Ordering::SeqCst), 1);
+
+        assert_eq!(
+            fetcher.input(2022, 2).unwrap_err().kind(),
+            ErrorKind::NotFound
+        );
+        assert!(fetcher
+            .input(2022, 3)
+            .unwrap_err()
+            .to_string()
+            .contains("rate limited"));
+        assert!(!root.path().join("input/2022/day2.txt").exists());
+    }
+
+    #[test]
+    fn session_errors() {
+        let root = tempfile::tempdir().unwrap();
+        let server = Server::start(inputs);
+        let fetcher = server.fetcher("wrong", root.path());
+        assert_eq!(
+            fetcher.input(2022, 1).unwrap_err().kind(),
+            ErrorKind::PermissionDenied
+        );
+
+        let mut fetcher = Fetcher::new(None, Box::new(Http));
+        fetcher.root = root.path().to_path_buf();
+        fetcher.base_url.clone_from(&server.url);
+        assert_eq!(
+            fetcher.input(2022, 1).unwrap_err().kind(),
+            ErrorKind::PermissionDenied
+        );
+        assert_eq!(
+            fetcher.input(2099, 1).unwrap_err().kind(),
+            ErrorKind::NotFound
+        );
+        assert_eq!(server.requests.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn throttle() {
+        let root = tempfile::tempdir().unwrap();
+        let server = Server::start(inputs);
+        let mut fetcher = server.fetcher("secret", root.path());
+        fetcher.interval = Duration::from_millis(200);
+        let start = Instant::now();
+        fetcher.input(2022, 2).unwrap_err();
+        fetcher.input(2022, 4).unwrap_err();
+        assert!(start.elapsed() >= Duration::from_millis(200));
+    }
+
+    #[test]
+    fn unlock_dates() {
+        let unlock = UNIX_EPOCH + Duration::from_hours(19_327 * 24 + 5);
+        assert_eq!(year_unlocked(2022, unlock), Some(true));
+        assert_eq!(year_unlocked(2021, unlock), Some(true));
+        assert_eq!(year_unlocked(2023, unlock), Some(false));
+        assert_eq!(
+            year_unlocked(2022, unlock - Duration::from_secs(1)),
+            Some(false)
+        );
+    }
+}
@@ -0,0 +1,196 @@
+//! C interface to the solvers, exported by the `cdylib`.
+//!
+//! build.rs generates its header from this file with cbindgen into `OUT_DIR`, and a test fails
+//! until the committed include/aoc.h matches it.
+
+use std::{ffi::c_char, ptr, slice, sync::LazyLock};
+
+use crate::{error::ErrorKind, registry::Registry, runner::catch_panic, years};
+
+static REGISTRY: LazyLock<Registry> = LazyLock::new(years::registry);
+
+/// What [`aoc_solve`] did.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AocStatus {
+    /// The answer was written to the output buffer.
+    Ok = 0,
+    /// No day is registered for this year and day.
+    UnknownDay = 1,
+    /// The part isn't 1 or 2, or a pointer is null with a length that isn't 0.
+    InvalidArgument = 2,
+    /// The input couldn't be parsed, and the error was written to the output buffer.
+    ParseError = 3,
+    /// The part has no answer for this input, and the error was written to the output buffer.
+    NoSolution = 4,
+    /// The day panicked, and the panic message was written to the output buffer.
+    Panic = 5,
+    /// The answer didn't fit in the output buffer, which holds as much of it as fits.
+    BufferTooSmall = 6,
+}
+
+/// A day that can be solved, as listed by [`aoc_days`].
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AocDay {
+    pub year: u16,
+    pub day: u8,
+}
+
+/// Writes the first `capacity` days that can be solved to `days`, in order, and returns how many
+/// there are in all.
+///
+/// # Safety
+///
+/// `days` must point to `capacity` writable days. It can be null when `capacity` is 0, to count
+/// the days before allocating room for them.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_days(days: *mut AocDay, capacity: usize) -> usize {
+    let all = REGISTRY
+        .days()
+        .map(|(year, day)| AocDay { year, day })
+        .collect::<Vec<_>>();
+    if !days.is_null() {
+        unsafe { ptr::copy_nonoverlapping(all.as_ptr(), days, capacity.min(all.len())) };
+    }
+    all.len()
+}
+
+/// Solves a part of a day on the `input_len` bytes at `input_ptr`.
+///
+/// The answer is written as a NUL-terminated string to `out_buf`, `out_len` bytes long with the
+/// NUL. Parse errors, missing solutions and panics are written there too, cut short if needed.
+///
+/// # Safety
+///
+/// `input_ptr` must point to `input_len` readable bytes and `out_buf` to `out_len` writable ones.
+/// Either can be null when its length is 0.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_solve(
+    year: u16,
+    day: u8,
+    part: u8,
+    input_ptr: *const u8,
+    input_len: usize,
+    out_buf: *mut c_char,
+    out_len: usize,
+) -> AocStatus {
+    if !(1..=2).contains(&part)
+        || (input_ptr.is_null() && input_len > 0)
+        || (out_buf.is_null() && out_len > 0)
+    {
+        return AocStatus::InvalidArgument;
+    }
+    let Some(solver) = REGISTRY.get(year, day) else {
+        return AocStatus::UnknownDay;
+    };
+    let input = if input_ptr.is_null() {
+        &[]
+    } else {
+        unsafe { slice::from_raw_parts(input_ptr, input_len) }
+    };
+    let out = if out_buf.is_null() {
+        &mut []
+    } else {
+        unsafe { slice::from_raw_parts_mut(out_buf.cast::<u8>(), out_len) }
+    };
+
+    let outcome = catch_panic(|| {
+        let parsed = solver.parse_bytes(input)?;
+        match part {
+            1 => parsed.into_part1(),
+            _ => parsed.into_part2(),
+        }
+    });
+    let (status, text) = match outcome {
+        Ok(Ok(answer)) => (AocStatus::Ok, answer),
+        Ok(Err(err)) => match err.kind {
+            ErrorKind::Parse { .. } => (AocStatus::ParseError, err.to_string()),
+            ErrorKind::NoSolution => (AocStatus::NoSolution, err.to_string()),
+        },
+        Err(panic) => (AocStatus::Panic, panic),
+    };
+    match (status, write(out, &text)) {
+        (AocStatus::Ok, false) => AocStatus::BufferTooSmall,
+        _ => status,
+    }
+}
+
+/// Copies `text` to `out` followed by a NUL, cutting it short to fit, and returns whether it all
+/// fit.
+fn write(out: &mut [u8], text: &str) -> bool {
+    let Some(room) = out.len().checked_sub(1) else {
+        return false;
+    };
+    let len = text.len().min(room);
+    out[..len].copy_from_slice(&text.as_bytes()[..len]);
+    out[len] = 0;
+    len == text.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::CStr;
+
+    use super::*;
+    use crate::years::y2022::YEAR;
+
+    fn solve(day: u8, part: u8, input: &[u8], out_len: usize) -> (AocStatus, String) {
+        let mut out = vec![b'?'; out_len];
+        let status = unsafe {
+            aoc_solve(
+                YEAR,
+                day,
+                part,
+                input.as_ptr(),
+                input.len(),
+                out.as_mut_ptr().cast(),
+                out.len(),
+            )
+        };
+        let text = CStr::from_bytes_until_nul(&out).map_or_else(
+            |_| String::new(),
+            |text| text.to_string_lossy().into_owned(),
+        );
+        (status, text)
+    }
+
+    #[test]
+    fn statuses() {
+        let input = b"1\n2\n\n4\n";
+        assert_eq!(solve(1, 1, input, 8), (AocStatus::Ok, "4".to_string()));
+        assert_eq!(solve(1, 2, input, 8).0, AocStatus::NoSolution);
+        assert_eq!(
+            solve(1, 1, b"1\nx", 64),
+            (
+                AocStatus::ParseError,
+                "line 2, column 1: expected a calorie count".to_string()
+            )
+        );
+        let (status, text) = solve(1, 1, b"1000\n2000\n", 3);
+        assert_eq!((status, text.as_str()), (AocStatus::BufferTooSmall, "30"));
+        assert_eq!(solve(1, 1, input, 0).0, AocStatus::BufferTooSmall);
+        assert_eq!(solve(25, 1, input, 8).0, AocStatus::UnknownDay);
+        assert_eq!(solve(1, 3, input, 8).0, AocStatus::InvalidArgument);
+        let status = unsafe { aoc_solve(YEAR, 1, 1, ptr::null(), 1, ptr::null_mut(), 0) };
+        assert_eq!(status, AocStatus::InvalidArgument);
+    }
+
+    #[test]
+    fn days() {
+        let count = unsafe { aoc_days(ptr::null_mut(), 0) };
+        let mut days = vec![AocDay { year: 0, day: 0 }; 2];
+        assert_eq!(unsafe { aoc_days(days.as_mut_ptr(), days.len()) }, count);
+        assert_eq!(days[0], AocDay { year: YEAR, day: 1 });
+        assert_eq!(count, REGISTRY.days().count());
+    }
+
+    #[test]
+    fn header() {
+        let generated = concat!(env!("OUT_DIR"), "/aoc.h");
+        assert!(
+            include_str!(concat!(env!("OUT_DIR"), "/aoc.h")) == include_str!("../include/aoc.h"),
+            "include/aoc.h is out of date with src/ffi.rs, copy {generated} over it"
+        );
+    }
+}
@@ -0,0 +1,230 @@
+use std::{
+    fmt::Display,
+    io::{Error, ErrorKind, Write},
+    path::Path,
+    process::Command,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::util::date::civil;
+
+/// Benchmark results of a day at some git revision, with mean times in nanoseconds.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Entry {
+    pub rev: String,
+    pub dirty: bool,
+    pub date: String,
+    pub year: u16,
+    pub day: u8,
+    pub parser: f64,
+    /// The parser from raw bytes, for days that have one.
+    #[serde(default)]
+    pub parser_bytes: Option<f64>,
+    pub part1: Option<f64>,
+    pub part2: Option<f64>,
+    #[serde(default)]
+    pub both: Option<f64>,
+}
+
+/// Every benchmark run so far, stored as JSON lines in the order they happened.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct History {
+    pub entries: Vec<Entry>,
+}
+
+impl History {
+    pub const PATH: &'static str = "bench-history.jsonl";
+
+    /// Loads the history stored at `path`, with a missing file holding no entries.
+    pub fn load(path: &Path) -> std::io::Result<Self> {
+        let content = match std::fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(Self::default()),
+            Err(e) => return Err(e),
+        };
+        let entries = content
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                serde_json::from_str(line).map_err(|e| Error::new(ErrorKind::InvalidData, e))
+            })
+            .collect::<std::io::Result<_>>()?;
+        Ok(Self { entries })
+    }
+
+    pub fn append(path: &Path, entries: &[Entry]) -> std::io::Result<()> {
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)?;
+        for entry in entries {
+            writeln!(file, "{}", serde_json::to_string(entry)?)?;
+        }
+        Ok(())
+    }
+
+    /// The latest results of a day measured on a clean checkout of `rev`.
+    #[must_use]
+    pub fn latest(&self, rev: &str, year: u16, day: u8) -> Option<&Entry> {
+        self.entries
+            .iter()
+            .rev()
+            .find(|e| !e.dirty && e.rev == rev && e.year == year && e.day == day)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Change {
+    pub year: u16,
+    pub day: u8,
+    pub step: &'static str,
+    pub before: f64,
+    pub after: f64,
+}
+
+impl Change {
+    #[must_use]
+    pub fn percent(&self) -> f64 {
+        (self.after - self.before) / self.before * 100.0
+    }
+
+    #[must_use]
+    pub fn regressed(&self, threshold: f64) -> bool {
+        self.percent() > threshold
+    }
+}
+
+impl Display for Change {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} Day {} {}: {:.1}µs -> {:.1}µs ({:+.1}%)",
+            self.year,
+            self.day,
+            self.step,
+            self.before / 1000.0,
+            self.after / 1000.0,
+            self.percent()
+        )
+    }
+}
+
+/// Changes of every step measured in both entries.
+#[must_use]
+pub fn compare(before: &Entry, after: &Entry) -> Vec<Change> {
+    [
+        ("parser", Some(before.parser), Some(after.parser)),
+        ("parser (bytes)", before.parser_bytes, after.parser_bytes),
+        ("part 1", before.part1, after.part1),
+        ("part 2", before.part2, after.part2),
+        ("both parts", before.both, after.both),
+    ]
+    .into_iter()
+    .filter_map(|(step, old, new)| {
+        Some(Change {
+            year: after.year,
+            day: after.day,
+            step,
+            before: old?,
+            after: new?,
+        })
+    })
+    .collect()
+}
+
+/// Resolves `rev` to a full commit hash.
+#[must_use]
+pub fn git_rev(rev: &str) -> Option<String> {
+    let output = Command::new("git")
+        .args(["rev-parse", "--verify", "--quiet"])
+        .arg(format!("{rev}^{{commit}}"))
+        .output()
+        .ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Whether tracked files have uncommitted changes.
+#[must_use]
+pub fn git_dirty() -> bool {
+    Command::new("git")
+        .args(["status", "--porcelain", "--untracked-files=no"])
+        .output()
+        .is_ok_and(|output| !output.stdout.is_empty())
+}
+
+/// Today's UTC date as `YYYY-MM-DD`.
+#[must_use]
+pub fn today() -> String {
+    let secs = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs());
+    date(secs)
+}
+
+fn date(unix_secs: u64) -> String {
+    let (year, month, day) = civil(unix_secs);
+    format!("{year:04}-{month:02}-{day:02}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(rev: &str, dirty: bool, parser: f64, part2: Option<f64>) -> Entry {
+        Entry {
+            rev: rev.to_string(),
+            dirty,
+            date: "2022-12-06".to_string(),
+            year: 2022,
+            day: 6,
+            parser,
+            parser_bytes: None,
+            part1: Some(1000.0),
+            part2,
+            both: None,
+        }
+    }
+
+    #[test]
+    fn latest_clean_entry() {
+        let history = History {
+            entries: vec![
+                entry("a", false, 1.0, None),
+                entry("a", false, 2.0, None),
+                entry("a", true, 3.0, None),
+                entry("b", false, 4.0, None),
+            ],
+        };
+        assert_eq!(history.latest("a", 2022, 6).map(|e| e.parser), Some(2.0));
+        assert_eq!(history.latest("a", 2022, 7), None);
+        assert_eq!(history.latest("c", 2022, 6), None);
+    }
+
+    #[test]
+    fn changes() {
+        let changes = compare(
+            &entry("a", false, 1000.0, Some(500.0)),
+            &entry("b", false, 1200.0, None),
+        );
+        assert_eq!(changes.len(), 2);
+        assert_eq!(
+            changes[0].to_string(),
+            "2022 Day 6 parser: 1.0µs -> 1.2µs (+20.0%)"
+        );
+        assert!(changes[0].regressed(10.0));
+        assert!(!changes[0].regressed(25.0));
+        assert!(!changes[1].regressed(0.0));
+    }
+
+    #[test]
+    fn dates() {
+        assert_eq!(date(0), "1970-01-01");
+        assert_eq!(date(951_782_400), "2000-02-29");
+        assert_eq!(date(1_670_284_800), "2022-12-06");
+    }
+}
@@ -1,15 +1,24 @@
 #![feature(associated_type_defaults)]
-#![feature(array_chunks)]
-#![feature(array_windows)]
 #![feature(iter_array_chunks)]
-#![feature(result_option_inspect)]
 #![warn(clippy::all, clippy::pedantic, clippy::nursery)]
 #![allow(
     clippy::wildcard_imports,
     clippy::default_trait_access,
-    clippy::cast_lossless
+    clippy::cast_lossless,
+    clippy::missing_errors_doc
 )]
 
-pub mod days;
+pub mod answers;
+pub mod error;
+pub mod fetch;
+pub mod ffi;
+pub mod history;
+pub mod memory;
+pub mod registry;
+pub mod runner;
+pub mod scaffold;
 pub mod solver;
+pub mod submit;
 pub(crate) mod util;
+pub mod visualize;
+pub mod years;
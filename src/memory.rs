@@ -0,0 +1,153 @@
+use std::{
+    alloc::{GlobalAlloc, Layout},
+    cell::Cell,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+thread_local! {
+    static COUNTERS: Cell<Counters> = const { Cell::new(Counters::ZERO) };
+}
+
+#[derive(Clone, Copy)]
+struct Counters {
+    allocations: u64,
+    bytes: u64,
+    current: i64,
+    peak: i64,
+}
+
+impl Counters {
+    const ZERO: Self = Self {
+        allocations: 0,
+        bytes: 0,
+        current: 0,
+        peak: 0,
+    };
+
+    #[allow(clippy::cast_possible_wrap)]
+    fn update(allocated: usize, freed: usize) {
+        COUNTERS.with(|counters| {
+            let mut c = counters.get();
+            if allocated > 0 {
+                c.allocations += 1;
+                c.bytes += allocated as u64;
+            }
+            c.current += allocated as i64 - freed as i64;
+            c.peak = c.peak.max(c.current);
+            counters.set(c);
+        });
+    }
+}
+
+/// Heap usage of a closure run by [`measure`]. A reallocation counts as one allocation of its
+/// new size, and the peak is on top of what the thread held before.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Usage {
+    pub allocations: u64,
+    pub bytes: u64,
+    pub peak: u64,
+}
+
+/// Wraps a global allocator to count the allocations of each thread once [`enable`] is called.
+pub struct Counting<A>(pub A);
+
+unsafe impl<A: GlobalAlloc> GlobalAlloc for Counting<A> {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { self.0.alloc(layout) };
+        if !ptr.is_null() && ENABLED.load(Ordering::Relaxed) {
+            Counters::update(layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { self.0.alloc_zeroed(layout) };
+        if !ptr.is_null() && ENABLED.load(Ordering::Relaxed) {
+            Counters::update(layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { self.0.dealloc(ptr, layout) };
+        if ENABLED.load(Ordering::Relaxed) {
+            Counters::update(0, layout.size());
+        }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = unsafe { self.0.realloc(ptr, layout, new_size) };
+        if !new_ptr.is_null() && ENABLED.load(Ordering::Relaxed) {
+            Counters::update(new_size, layout.size());
+        }
+        new_ptr
+    }
+}
+
+/// Starts counting allocations, which only happens when [`Counting`] is the global allocator.
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+/// Runs `f` and returns the heap usage of the current thread meanwhile, if counting is enabled.
+#[allow(clippy::cast_sign_loss)]
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<Usage>) {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return (f(), None);
+    }
+    let outer = COUNTERS.replace(Counters::ZERO);
+    let result = f();
+    let inner = COUNTERS.get();
+    COUNTERS.set(Counters {
+        allocations: outer.allocations + inner.allocations,
+        bytes: outer.bytes + inner.bytes,
+        current: outer.current + inner.current,
+        peak: outer.peak.max(outer.current + inner.peak),
+    });
+    let usage = Usage {
+        allocations: inner.allocations,
+        bytes: inner.bytes,
+        peak: inner.peak.max(0) as u64,
+    };
+    (result, Some(usage))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{alloc::System, hint::black_box};
+
+    use super::*;
+
+    #[global_allocator]
+    static ALLOCATOR: Counting<System> = Counting(System);
+
+    #[test]
+    fn usage() {
+        enable();
+        let ((), usage) = measure(|| {
+            let (small, inner) = measure(|| black_box(vec![0_u8; 100]));
+            assert_eq!(
+                inner,
+                Some(Usage {
+                    allocations: 1,
+                    bytes: 100,
+                    peak: 100
+                })
+            );
+            drop(black_box(vec![0_u8; 1000]));
+            let mut grown = black_box(Vec::<u8>::with_capacity(10));
+            grown.reserve_exact(20);
+            drop((small, grown));
+        });
+        assert_eq!(
+            usage,
+            Some(Usage {
+                allocations: 4,
+                bytes: 1130,
+                peak: 1100
+            })
+        );
+    }
+}
@@ -0,0 +1,184 @@
+use std::collections::BTreeMap;
+
+use crate::{
+    solver::{erase, erase_bytes, erase_variant, BytesSolver, DynSolver, Solver, Variant},
+    visualize::{self, DynVisualize, Visualize},
+};
+
+/// The name of the implementation a day is registered with, next to its variants.
+pub const DEFAULT_VARIANT: &str = "default";
+
+type Variants = Vec<(&'static str, Box<dyn DynSolver>)>;
+
+#[derive(Default)]
+pub struct Registry {
+    solvers: BTreeMap<(u16, u8), Box<dyn DynSolver>>,
+    variants: BTreeMap<(u16, u8), Variants>,
+    visualizers: BTreeMap<(u16, u8), Box<dyn DynVisualize>>,
+}
+
+impl Registry {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn register<S: for<'a> Solver<'a> + 'static>(&mut self, year: u16, day: u8) -> &mut Self {
+        self.solvers.insert((year, day), erase::<S>());
+        self
+    }
+
+    /// Registers a day that also parses raw bytes, which the runner hands it without validating
+    /// them as UTF-8.
+    pub fn register_bytes<S: for<'a> BytesSolver<'a> + 'static>(
+        &mut self,
+        year: u16,
+        day: u8,
+    ) -> &mut Self {
+        self.solvers.insert((year, day), erase_bytes::<S>());
+        self
+    }
+
+    /// Registers another implementation of the parts of a day under `name`.
+    pub fn variant<S, V>(&mut self, year: u16, day: u8, name: &'static str) -> &mut Self
+    where
+        S: for<'a> Solver<'a> + 'static,
+        V: for<'a> Variant<'a, S> + 'static,
+    {
+        self.variants
+            .entry((year, day))
+            .or_default()
+            .push((name, erase_variant::<S, V>()));
+        self
+    }
+
+    /// Registers the visualization of a day, next to its solver.
+    pub fn visualize<S: for<'a> Visualize<'a> + 'static>(
+        &mut self,
+        year: u16,
+        day: u8,
+    ) -> &mut Self {
+        self.visualizers
+            .insert((year, day), visualize::erase::<S>());
+        self
+    }
+
+    #[must_use]
+    pub fn get(&self, year: u16, day: u8) -> Option<&dyn DynSolver> {
+        self.solvers.get(&(year, day)).map(Box::as_ref)
+    }
+
+    /// The implementation of a day called `name`, [`DEFAULT_VARIANT`] being the day itself.
+    #[must_use]
+    pub fn get_variant(&self, year: u16, day: u8, name: &str) -> Option<&dyn DynSolver> {
+        self.variants(year, day)
+            .find(|&(variant, _)| variant == name)
+            .map(|(_, solver)| solver)
+    }
+
+    /// Every implementation of a day, starting with the day itself under [`DEFAULT_VARIANT`].
+    pub fn variants(&self, year: u16, day: u8) -> impl Iterator<Item = (&str, &dyn DynSolver)> {
+        let variants = self.variants.get(&(year, day)).into_iter().flatten();
+        self.get(year, day)
+            .map(|solver| (DEFAULT_VARIANT, solver))
+            .into_iter()
+            .chain(variants.map(|(name, solver)| (*name, solver.as_ref())))
+    }
+
+    #[must_use]
+    pub fn visualizer(&self, year: u16, day: u8) -> Option<&dyn DynVisualize> {
+        self.visualizers.get(&(year, day)).map(Box::as_ref)
+    }
+
+    pub fn days(&self) -> impl Iterator<Item = (u16, u8)> + '_ {
+        self.solvers.keys().copied()
+    }
+
+    pub fn years(&self) -> impl Iterator<Item = u16> + '_ {
+        let mut years = self.days().map(|(year, _)| year).collect::<Vec<_>>();
+        years.dedup();
+        years.into_iter()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = ((u16, u8), &dyn DynSolver)> {
+        self.solvers
+            .iter()
+            .map(|(&key, solver)| (key, solver.as_ref()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::years::{
+        self,
+        y2022::{day1::Day1, day2::Day2, YEAR},
+    };
+
+    #[test]
+    fn lookup() {
+        let mut registry = Registry::new();
+        registry.register::<Day1>(YEAR, 1);
+        assert!(registry.get(YEAR, 1).is_some());
+        assert!(registry.get(YEAR, 2).is_none());
+        assert!(registry.get(YEAR + 1, 1).is_none());
+    }
+
+    #[test]
+    fn years_side_by_side() {
+        let mut registry = Registry::new();
+        registry
+            .register::<Day1>(YEAR, 1)
+            .register::<Day2>(YEAR - 1, 1);
+        assert!(registry.years().eq([YEAR - 1, YEAR]));
+        let input = "A Y\nB X\nC Z";
+        assert!(registry.get(YEAR, 1).unwrap().parse(input).is_err());
+        assert!(registry.get(YEAR - 1, 1).unwrap().parse(input).is_ok());
+    }
+
+    #[test]
+    fn bytes() {
+        let registry = years::registry();
+        let (day1, day2) = (
+            registry.get(YEAR, 1).unwrap(),
+            registry.get(YEAR, 2).unwrap(),
+        );
+        assert!(day1.parses_bytes() && !day2.parses_bytes());
+        let parsed = day1.parse_bytes(b"1\n2\n\n4\n").unwrap();
+        assert_eq!(parsed.part1(), Ok("4".to_string()));
+        assert!(day1.parse_bytes(b"1\n\xff").is_err());
+        assert!(day2.parse_bytes(b"A Y\n").is_ok());
+    }
+
+    #[test]
+    fn run_erased() {
+        let registry = years::registry();
+        let parsed = registry
+            .get(YEAR, 6)
+            .unwrap()
+            .parse("bvwbjplbgvbhsrlpgdmjqwftvncz");
+        let parsed = parsed.unwrap();
+        assert_eq!(parsed.part1(), Ok("5".to_string()));
+        assert_eq!(parsed.into_part2(), Ok("23".to_string()));
+    }
+
+    #[test]
+    fn variants() {
+        let registry = years::registry();
+        let names = registry.variants(YEAR, 6).map(|(name, _)| name);
+        assert!(names.eq([DEFAULT_VARIANT, "window"]));
+        assert!(registry.get_variant(YEAR, 6, "simd").is_none());
+        let input = "mjqjpqmgbljsphdztnvjfqwrcgsmlb";
+        for (_, solver) in registry.variants(YEAR, 6) {
+            let parsed = solver.parse(input).unwrap();
+            assert_eq!(parsed.part1(), Ok("7".to_string()));
+            assert_eq!(parsed.into_part2(), Ok("19".to_string()));
+        }
+    }
+
+    #[test]
+    fn all_days() {
+        let registry = years::registry();
+        assert!(registry.days().eq((1..=15).map(|day| (YEAR, day))));
+    }
+}
@@ -0,0 +1,618 @@
+use std::{
+    cell::{Cell, RefCell},
+    fmt::Display,
+    io::Read,
+    panic::{AssertUnwindSafe, PanicHookInfo},
+    path::{Path, PathBuf},
+    str::FromStr,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Once,
+    },
+    time::{Duration, Instant},
+};
+
+use serde::{Serialize, Serializer};
+
+use crate::{
+    answers::{Answers, Verdict},
+    memory::{measure, Usage},
+    solver::DynSolver,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DayRange {
+    pub first: u8,
+    pub last: u8,
+}
+
+impl DayRange {
+    #[must_use]
+    pub const fn contains(&self, day: u8) -> bool {
+        self.first <= day && day <= self.last
+    }
+}
+
+impl FromStr for DayRange {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let day = |day: &str| {
+            day.trim()
+                .parse::<u8>()
+                .ok()
+                .filter(|day| (1..=25).contains(day))
+                .ok_or_else(|| format!("`{day}` is not a day between 1 and 25"))
+        };
+        let (first, last) = match s.split_once('-') {
+            Some((first, last)) => (day(first)?, day(last)?),
+            None => (day(s)?, day(s)?),
+        };
+        if first > last {
+            return Err(format!("`{s}` is an empty range"));
+        }
+        Ok(Self { first, last })
+    }
+}
+
+#[must_use]
+pub fn input_path(year: u16, day: u8) -> PathBuf {
+    PathBuf::from(format!("input/{year}/day{day}.txt"))
+}
+
+/// Identifies an input by its content, using 64-bit FNV-1a so that the hash stays stable across
+/// builds and platforms.
+#[must_use]
+pub fn input_hash(input: &[u8]) -> String {
+    let hash = input.iter().fold(0xcbf2_9ce4_8422_2325_u64, |hash, &byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+    });
+    format!("{hash:016x}")
+}
+
+/// Reads a puzzle input as raw bytes, with `-` standing for stdin. Solvers that parse text
+/// validate it as UTF-8 themselves, see [`DynSolver::parse_bytes`].
+pub fn read_input(path: &Path) -> std::io::Result<Vec<u8>> {
+    if path == Path::new("-") {
+        let mut input = Vec::new();
+        std::io::stdin().read_to_end(&mut input)?;
+        Ok(input)
+    } else {
+        std::fs::read(path)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    Pass,
+    Fail,
+    Unchecked,
+    Recorded,
+    Error,
+    Panic,
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::Pass => "pass",
+            Self::Fail => "fail",
+            Self::Unchecked => "unchecked",
+            Self::Recorded => "recorded",
+            Self::Error => "error",
+            Self::Panic => "panic",
+        };
+        f.write_str(name)
+    }
+}
+
+/// Outcome of solving one part of a day.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Record {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    #[serde(rename = "parse_ns", serialize_with = "nanos")]
+    pub parse_time: Duration,
+    #[serde(rename = "solve_ns", serialize_with = "nanos")]
+    pub solve_time: Duration,
+    /// Time of [`Solver::solve_both`](crate::solver::Solver::solve_both) for days that have it,
+    /// when both parts are solved.
+    #[serde(rename = "both_ns", serialize_with = "optional_nanos")]
+    pub both_time: Option<Duration>,
+    pub answer: Option<String>,
+    pub expected: Option<String>,
+    pub status: Status,
+    pub error: Option<String>,
+    pub input_hash: String,
+    #[serde(skip)]
+    pub parse_memory: Option<Usage>,
+    #[serde(skip)]
+    pub solve_memory: Option<Usage>,
+}
+
+impl Record {
+    /// A part that could not be solved at all, e.g. because its input is missing.
+    #[must_use]
+    pub const fn failure(year: u16, day: u8, part: u8, error: String) -> Self {
+        Self {
+            year,
+            day,
+            part,
+            parse_time: Duration::ZERO,
+            solve_time: Duration::ZERO,
+            both_time: None,
+            answer: None,
+            expected: None,
+            status: Status::Error,
+            error: Some(error),
+            input_hash: String::new(),
+            parse_memory: None,
+            solve_memory: None,
+        }
+    }
+}
+
+#[allow(clippy::trivially_copy_pass_by_ref)]
+fn nanos<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u128(duration.as_nanos())
+}
+
+#[allow(clippy::ref_option)]
+fn optional_nanos<S: Serializer>(
+    duration: &Option<Duration>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    duration
+        .map(|duration| duration.as_nanos())
+        .serialize(serializer)
+}
+
+/// Solves the given parts of a day, verifying the answers against the known ones and recording
+/// the missing ones when `record_new` is set. A parse error is reported on each part.
+pub fn run_day(
+    year: u16,
+    day: u8,
+    solver: &dyn DynSolver,
+    input: &[u8],
+    parts: &[u8],
+    answers: &mut Answers,
+    record_new: bool,
+) -> Vec<Record> {
+    let mut records = solve_day(year, day, solver, input, parts);
+    verify(&mut records, answers, record_new);
+    records
+}
+
+/// Solves the given parts of a day, leaving the answers unchecked.
+pub fn solve_day(
+    year: u16,
+    day: u8,
+    solver: &dyn DynSolver,
+    input: &[u8],
+    parts: &[u8],
+) -> Vec<Record> {
+    let input_hash = input_hash(input);
+    let new_record = |part, parse_time, parse_memory| Record {
+        year,
+        day,
+        part,
+        parse_time,
+        solve_time: Duration::ZERO,
+        both_time: None,
+        answer: None,
+        expected: None,
+        status: Status::Error,
+        error: None,
+        input_hash: input_hash.clone(),
+        parse_memory,
+        solve_memory: None,
+    };
+
+    let start_time = Instant::now();
+    let (parsed, parse_memory) = measure(|| catch_panic(|| solver.parse_bytes(input)));
+    let parse_time = start_time.elapsed();
+    let parsed = match flatten(day, parsed) {
+        Ok(parsed) => parsed,
+        Err((status, err)) => {
+            return parts
+                .iter()
+                .map(|&part| Record {
+                    status,
+                    error: Some(err.clone()),
+                    ..new_record(part, parse_time, parse_memory)
+                })
+                .collect();
+        }
+    };
+
+    let both = (parts.contains(&1) && parts.contains(&2))
+        .then(|| {
+            let start_time = Instant::now();
+            let both = catch_panic(|| parsed.solve_both()).transpose()?;
+            Some((flatten(day, both), start_time.elapsed()))
+        })
+        .flatten();
+
+    // The last part consumes the parsed data, the ones before borrow it.
+    let mut parsed = Some(parsed);
+    let mut records = parts
+        .iter()
+        .enumerate()
+        .map(|(i, &part)| {
+            let mut record = new_record(part, parse_time, parse_memory);
+            let owned = if i + 1 == parts.len() {
+                parsed.take()
+            } else {
+                None
+            };
+            let borrowed = parsed.as_deref();
+            let start_time = Instant::now();
+            let (answer, solve_memory) = measure(|| {
+                catch_panic(|| match (owned, borrowed, part) {
+                    (Some(parsed), _, 1) => parsed.into_part1(),
+                    (Some(parsed), _, _) => parsed.into_part2(),
+                    (None, Some(parsed), 1) => parsed.part1(),
+                    (None, Some(parsed), _) => parsed.part2(),
+                    (None, None, _) => unreachable!(),
+                })
+            });
+            record.solve_time = start_time.elapsed();
+            record.solve_memory = solve_memory;
+            match flatten(day, answer) {
+                Ok(answer) => {
+                    record.status = Status::Unchecked;
+                    record.answer = Some(answer);
+                }
+                Err((status, err)) => {
+                    record.status = status;
+                    record.error = Some(err);
+                }
+            }
+            record
+        })
+        .collect::<Vec<_>>();
+
+    if let Some((both, both_time)) = both {
+        check_both(&mut records, &both, both_time);
+    }
+    records
+}
+
+/// Compares the answers of [`Solver::solve_both`](crate::solver::Solver::solve_both) with the
+/// ones of the parts solved separately.
+fn check_both(
+    records: &mut [Record],
+    both: &Result<[String; 2], (Status, String)>,
+    both_time: Duration,
+) {
+    for record in records.iter_mut() {
+        record.both_time = Some(both_time);
+        if record.status != Status::Unchecked {
+            continue;
+        }
+        match both {
+            Ok(answers)
+                if record.answer.as_ref() != Some(&answers[usize::from(record.part - 1)]) =>
+            {
+                record.status = Status::Error;
+                record.error = Some(format!(
+                    "solving both parts at once answers {}",
+                    answers[usize::from(record.part - 1)]
+                ));
+            }
+            Ok(_) => {}
+            Err((status, err)) => {
+                record.status = *status;
+                record.error = Some(format!("solving both parts at once: {err}"));
+            }
+        }
+    }
+}
+
+/// Solves the parts of `records` again with each of `variants`, and fails a part whose answer one
+/// of them contradicts or cannot give.
+pub fn cross_check(records: &mut [Record], variants: &[(&str, &dyn DynSolver)], input: &[u8]) {
+    let Some(day) = records.first().map(|record| record.day) else {
+        return;
+    };
+    for &(name, solver) in variants {
+        let parsed = flatten(day, catch_panic(|| solver.parse_bytes(input)));
+        for record in records.iter_mut() {
+            if record.status != Status::Unchecked {
+                continue;
+            }
+            let answer = parsed.as_ref().map_err(Clone::clone).and_then(|parsed| {
+                flatten(
+                    day,
+                    catch_panic(|| match record.part {
+                        1 => parsed.part1(),
+                        _ => parsed.part2(),
+                    }),
+                )
+            });
+            match answer {
+                Ok(answer) if record.answer.as_ref() != Some(&answer) => {
+                    record.status = Status::Error;
+                    record.error = Some(format!("variant `{name}` answers {answer}"));
+                }
+                Ok(_) => {}
+                Err((status, err)) => {
+                    record.status = status;
+                    record.error = Some(format!("variant `{name}`: {err}"));
+                }
+            }
+        }
+    }
+}
+
+/// Checks the unchecked answers of `records` against the known ones, see [`Answers::verify`].
+pub fn verify(records: &mut [Record], answers: &mut Answers, record_new: bool) {
+    for record in records {
+        let (Status::Unchecked, Some(answer)) = (record.status, &record.answer) else {
+            continue;
+        };
+        let verdict = answers.verify(
+            record.day,
+            &record.input_hash,
+            record.part,
+            answer,
+            record_new,
+        );
+        (record.status, record.expected) = match verdict {
+            Verdict::Pass => (Status::Pass, Some(answer.clone())),
+            Verdict::Fail { expected } => (Status::Fail, Some(expected)),
+            Verdict::Unchecked => (Status::Unchecked, None),
+            Verdict::Recorded => (Status::Recorded, None),
+        };
+    }
+}
+
+/// Maps `f` over `items` on up to `jobs` threads, keeping the results in order.
+pub fn parallel_map<T: Sync, R: Send>(
+    items: &[T],
+    jobs: usize,
+    f: impl Fn(&T) -> R + Sync,
+) -> Vec<R> {
+    let next = AtomicUsize::new(0);
+    let mut results = std::thread::scope(|scope| {
+        let workers = (0..jobs.clamp(1, items.len().max(1)))
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut results = Vec::new();
+                    loop {
+                        let i = next.fetch_add(1, Ordering::Relaxed);
+                        let Some(item) = items.get(i) else {
+                            break results;
+                        };
+                        results.push((i, f(item)));
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+        workers
+            .into_iter()
+            .flat_map(|worker| {
+                worker
+                    .join()
+                    .unwrap_or_else(|e| std::panic::resume_unwind(e))
+            })
+            .collect::<Vec<_>>()
+    });
+    results.sort_unstable_by_key(|&(i, _)| i);
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+fn flatten<T>(
+    day: u8,
+    result: Result<crate::error::Result<T>, String>,
+) -> Result<T, (Status, String)> {
+    match result {
+        Ok(Ok(value)) => Ok(value),
+        Ok(Err(err)) => Err((Status::Error, err.with_day(day).to_string())),
+        Err(panic) => Err((Status::Panic, panic)),
+    }
+}
+
+thread_local! {
+    static CATCHING: Cell<bool> = const { Cell::new(false) };
+    static PANIC: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Runs `f`, turning a panic into a description of its message and location instead of
+/// printing it. This needs unwinding, so it can't catch anything with `panic = "abort"`.
+pub fn catch_panic<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    static HOOK: Once = Once::new();
+    HOOK.call_once(|| {
+        let default_hook = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            if CATCHING.get() {
+                PANIC.set(Some(describe(info)));
+            } else {
+                default_hook(info);
+            }
+        }));
+    });
+
+    let catching = CATCHING.replace(true);
+    let result = std::panic::catch_unwind(AssertUnwindSafe(f));
+    CATCHING.set(catching);
+    result.map_err(|_| PANIC.take().unwrap_or_else(|| "panicked".to_string()))
+}
+
+fn describe(info: &PanicHookInfo) -> String {
+    let message = info.payload_as_str().unwrap_or("Box<dyn Any>");
+    info.location().map_or_else(
+        || format!("panicked: {message}"),
+        |location| format!("panicked at {location}: {message}"),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::{Solver, Variant};
+
+    #[test]
+    fn day_ranges() {
+        assert_eq!("5".parse(), Ok(DayRange { first: 5, last: 5 }));
+        assert_eq!("3-12".parse(), Ok(DayRange { first: 3, last: 12 }));
+        assert!("12-3".parse::<DayRange>().is_err());
+        assert!("0".parse::<DayRange>().is_err());
+        assert!("26".parse::<DayRange>().is_err());
+        assert!("a".parse::<DayRange>().is_err());
+        assert!(DayRange { first: 3, last: 12 }.contains(12));
+        assert!(!DayRange { first: 3, last: 12 }.contains(2));
+    }
+
+    #[test]
+    fn hash() {
+        assert_eq!(input_hash(b""), "cbf29ce484222325");
+        assert_eq!(input_hash(b"a"), "af63dc4c8601ec8c");
+    }
+
+    #[test]
+    fn records() {
+        let registry = crate::years::registry();
+        let solver = registry.get(crate::years::y2022::YEAR, 6).unwrap();
+        let input = b"mjqjpqmgbljsphdztnvjfqwrcgsmlb";
+        let mut answers = Answers::default();
+
+        let records = run_day(2022, 6, solver, input, &[1, 2], &mut answers, true);
+        let statuses = records.iter().map(|r| r.status).collect::<Vec<_>>();
+        assert_eq!(statuses, [Status::Recorded, Status::Recorded]);
+        assert_eq!(records[0].answer.as_deref(), Some("7"));
+        assert_eq!(records[1].input_hash, input_hash(input));
+
+        let records = run_day(2022, 6, solver, input, &[2], &mut answers, false);
+        assert_eq!(records[0].status, Status::Pass);
+        assert_eq!(records[0].expected.as_deref(), Some("19"));
+
+        let records = run_day(2022, 6, solver, b"", &[1], &mut answers, false);
+        assert_eq!(records[0].status, Status::Error);
+        assert!(records[0].answer.is_none());
+
+        let records = run_day(2022, 6, solver, b"mjq\xff", &[1], &mut answers, false);
+        assert_eq!(
+            records[0].error.as_deref(),
+            Some("day 6: line 1, column 4: expected UTF-8 text")
+        );
+    }
+
+    struct Panicking;
+
+    impl<'a> Solver<'a> for Panicking {
+        type Parsed = &'a str;
+        type Output = usize;
+
+        fn parse(input: &'a str) -> crate::error::Result<Self::Parsed> {
+            Ok(input)
+        }
+
+        fn part1(data: &Self::Parsed) -> crate::error::Result<Self::Output> {
+            Ok(data.len())
+        }
+
+        fn part2(data: &Self::Parsed) -> crate::error::Result<Self::Output> {
+            panic!("no part 2 for {data}")
+        }
+    }
+
+    #[test]
+    fn panics() {
+        let solver = crate::solver::erase::<Panicking>();
+        let mut answers = Answers::default();
+        let records = run_day(2022, 1, &*solver, b"abc", &[1, 2], &mut answers, false);
+        assert_eq!(records[0].status, Status::Unchecked);
+        assert_eq!(records[1].status, Status::Panic);
+        let error = records[1].error.as_deref().unwrap();
+        assert!(error.starts_with("panicked at src/runner.rs:"), "{error}");
+        assert!(error.ends_with(": no part 2 for abc"), "{error}");
+
+        assert_eq!(catch_panic(|| 1), Ok(1));
+    }
+
+    struct Both;
+
+    impl<'a> Solver<'a> for Both {
+        type Parsed = &'a str;
+        type Output = usize;
+
+        fn parse(input: &'a str) -> crate::error::Result<Self::Parsed> {
+            Ok(input)
+        }
+
+        fn part1(data: &Self::Parsed) -> crate::error::Result<Self::Output> {
+            Ok(data.len())
+        }
+
+        fn part2(data: &Self::Parsed) -> crate::error::Result<Self::Output> {
+            Ok(data.len())
+        }
+
+        fn solve_both(
+            data: &Self::Parsed,
+        ) -> Option<crate::error::Result<(Self::Output, Self::Output)>> {
+            Some(Ok((data.len(), data.len() + 1)))
+        }
+    }
+
+    #[test]
+    fn both_parts() {
+        let solver = crate::solver::erase::<Both>();
+        let records = solve_day(2022, 1, &*solver, b"abc", &[1, 2]);
+        assert!(records.iter().all(|record| record.both_time.is_some()));
+        assert_eq!(records[0].status, Status::Unchecked);
+        assert_eq!(records[1].status, Status::Error);
+        assert_eq!(
+            records[1].error.as_deref(),
+            Some("solving both parts at once answers 4")
+        );
+
+        let records = solve_day(2022, 1, &*solver, b"abc", &[2]);
+        assert_eq!(records[0].status, Status::Unchecked);
+        assert_eq!(records[0].both_time, None);
+
+        let solver = crate::solver::erase::<Panicking>();
+        let records = solve_day(2022, 1, &*solver, b"abc", &[1, 2]);
+        assert_eq!(records[0].both_time, None);
+    }
+
+    struct Off;
+
+    impl Variant<'_, Both> for Off {
+        fn part1(data: &&str) -> crate::error::Result<usize> {
+            Ok(data.len())
+        }
+
+        fn part2(data: &&str) -> crate::error::Result<usize> {
+            Ok(data.len() + 1)
+        }
+    }
+
+    #[test]
+    fn variants() {
+        let solver = crate::solver::erase::<Panicking>();
+        let variant = crate::solver::erase_variant::<Both, Off>();
+        let mut records = solve_day(2022, 1, &*solver, b"abc", &[1, 2]);
+        cross_check(&mut records, &[("off", &*variant)], b"abc");
+        assert_eq!(records[0].status, Status::Unchecked);
+        assert_eq!(records[1].status, Status::Panic);
+
+        let solver = crate::solver::erase::<Both>();
+        let mut records = solve_day(2022, 1, &*solver, b"abc", &[2]);
+        cross_check(&mut records, &[("off", &*variant)], b"abc");
+        assert_eq!(records[0].status, Status::Error);
+        assert_eq!(records[0].error.as_deref(), Some("variant `off` answers 4"));
+    }
+
+    #[test]
+    fn ordered_parallel_map() {
+        let items = (0..100).collect::<Vec<u64>>();
+        let squares = items.iter().map(|x| x * x).collect::<Vec<_>>();
+        assert_eq!(parallel_map(&items, 4, |x| x * x), squares);
+        assert_eq!(parallel_map(&items, 1, |x| x * x), squares);
+        assert!(parallel_map(&[] as &[u64], 4, |x| x * x).is_empty());
+    }
+}
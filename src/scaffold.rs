@@ -0,0 +1,231 @@
+use std::{
+    fs,
+    io::{Error, ErrorKind, Result},
+    path::{Path, PathBuf},
+};
+
+use regex::Regex;
+
+const TEMPLATE: &str = include_str!("years/template.rs");
+
+/// Sets up day `day` of `year` in the crate at `root`: its module from the template, its
+/// registration, an empty input file and a first example.
+///
+/// Existing inputs and examples are kept, but an existing day is never overwritten.
+pub fn new_day(root: &Path, year: u16, day: u8) -> Result<Vec<PathBuf>> {
+    let years = root.join("src/years");
+    let dir = years.join(format!("y{year}"));
+    let module = dir.join(format!("day{day}.rs"));
+    if module.exists() {
+        return Err(Error::new(
+            ErrorKind::AlreadyExists,
+            format!("{} already exists", module.display()),
+        ));
+    }
+
+    let mut created = Vec::new();
+    let mod_rs = dir.join("mod.rs");
+    if mod_rs.exists() {
+        let content = fs::read_to_string(&mod_rs)?;
+        fs::write(&mod_rs, add_day(&content, day)?)?;
+    } else {
+        let registry = years.join("mod.rs");
+        let content = add_year(&fs::read_to_string(&registry)?, year)?;
+        fs::create_dir_all(&dir)?;
+        fs::write(&mod_rs, add_day(&new_year(year), day)?)?;
+        fs::write(&registry, content)?;
+        created.push(mod_rs);
+    }
+    fs::write(&module, TEMPLATE.replace("NUM", &day.to_string()))?;
+    created.push(module);
+
+    let examples = root.join(format!("examples/{year}/day{day}"));
+    for (path, content) in [
+        (root.join(format!("input/{year}/day{day}.txt")), ""),
+        (examples.join("1.txt"), ""),
+        (examples.join("1.toml"), "# part1 = \"\"\n# part2 = \"\"\n"),
+    ] {
+        if !path.exists() {
+            fs::create_dir_all(path.parent().unwrap_or(root))?;
+            fs::write(&path, content)?;
+            created.push(path);
+        }
+    }
+    Ok(created)
+}
+
+fn new_year(year: u16) -> String {
+    format!(
+        "use crate::registry::Registry;\n\n\
+         pub const YEAR: u16 = {year};\n\n\
+         pub fn register(registry: &mut Registry) {{\n    registry;\n}}\n"
+    )
+}
+
+/// Adds `pub mod day<N>;` and registers the day in the chain of `registry.register` calls of a
+/// year module.
+fn add_day(content: &str, day: u8) -> Result<String> {
+    if content.lines().any(|l| l == format!("pub mod day{day};")) {
+        return Err(Error::new(
+            ErrorKind::AlreadyExists,
+            format!("day {day} is already declared"),
+        ));
+    }
+    let content = add_module(content, &format!("day{day}"))?;
+    let chain = Regex::new(r"registry(\s*\.register(_bytes)?::<day\d+::Day\d+>\(YEAR, \d+\))*;")
+        .unwrap()
+        .find(&content)
+        .ok_or_else(|| invalid("no `registry.register` calls"))?;
+
+    // Days keep the method they are registered with, `register_bytes` included.
+    let mut days = Regex::new(r"\.(register(?:_bytes)?)::<day\d+::Day\d+>\(YEAR, (\d+)\)")
+        .unwrap()
+        .captures_iter(chain.as_str())
+        .map(|captures| (captures[2].parse::<u8>().unwrap(), captures[1].to_string()))
+        .collect::<Vec<_>>();
+    days.push((day, "register".to_string()));
+    days.sort_unstable();
+
+    let calls = days
+        .iter()
+        .map(|(day, method)| format!(".{method}::<day{day}::Day{day}>(YEAR, {day})"))
+        .collect::<Vec<_>>();
+    let new_chain = if let [call] = calls.as_slice() {
+        format!("registry{call};")
+    } else {
+        format!("registry\n        {};", calls.join("\n        "))
+    };
+    Ok(format!(
+        "{}{}{}",
+        &content[..chain.start()],
+        new_chain,
+        &content[chain.end()..]
+    ))
+}
+
+/// Adds `pub mod y<year>;` and calls its `register` from `years::registry()`.
+fn add_year(content: &str, year: u16) -> Result<String> {
+    let content = add_module(content, &format!("y{year}"))?;
+    let call = format!("    y{year}::register(&mut registry);");
+    let mut lines = content.lines().collect::<Vec<_>>();
+    let calls = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, l)| {
+            l.trim_start().starts_with('y') && l.ends_with("::register(&mut registry);")
+        })
+        .map(|(i, _)| i)
+        .collect::<Vec<_>>();
+    let at = calls
+        .iter()
+        .rfind(|&&i| lines[i] < call.as_str())
+        .map(|i| i + 1)
+        .or_else(|| calls.first().copied())
+        .or_else(|| {
+            lines
+                .iter()
+                .position(|l| l.trim() == "let mut registry = Registry::new();")
+                .map(|i| i + 1)
+        })
+        .ok_or_else(|| invalid("no `registry` function"))?;
+    lines.insert(at, &call);
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Adds `pub mod <name>;` to the sorted block of module declarations, or after the imports.
+fn add_module(content: &str, name: &str) -> Result<String> {
+    let line = format!("pub mod {name};");
+    let mut lines = content.lines().collect::<Vec<_>>();
+    let modules = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, l)| l.starts_with("pub mod "))
+        .map(|(i, _)| i)
+        .collect::<Vec<_>>();
+    let at = if let Some(&last) = modules.last() {
+        modules
+            .iter()
+            .copied()
+            .find(|&i| lines[i]["pub mod ".len()..].trim_end_matches(';') > name)
+            .unwrap_or(last + 1)
+    } else {
+        let imports = lines
+            .iter()
+            .rposition(|l| l.starts_with("use "))
+            .ok_or_else(|| invalid("no imports"))?;
+        lines.insert(imports + 1, "");
+        imports + 2
+    };
+    lines.insert(at, &line);
+    if modules.is_empty() && lines.get(at + 1).is_some_and(|l| !l.is_empty()) {
+        lines.insert(at + 1, "");
+    }
+    Ok(lines.join("\n") + "\n")
+}
+
+fn invalid(message: &str) -> Error {
+    Error::new(ErrorKind::InvalidData, message.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scaffold() {
+        let root = tempfile::tempdir().unwrap();
+        let root = root.path();
+        fs::create_dir_all(root.join("src/years/y2022")).unwrap();
+        fs::create_dir_all(root.join("input/2022")).unwrap();
+        fs::write(root.join("src/years/mod.rs"), include_str!("years/mod.rs")).unwrap();
+        fs::write(
+            root.join("src/years/y2022/mod.rs"),
+            include_str!("years/y2022/mod.rs"),
+        )
+        .unwrap();
+        fs::write(root.join("input/2022/day16.txt"), "input").unwrap();
+
+        let created = new_day(root, 2022, 16).unwrap();
+        assert_eq!(
+            created,
+            [
+                root.join("src/years/y2022/day16.rs"),
+                root.join("examples/2022/day16/1.txt"),
+                root.join("examples/2022/day16/1.toml"),
+            ]
+        );
+        let module = fs::read_to_string(root.join("src/years/y2022/day16.rs")).unwrap();
+        assert!(module.contains("impl<'a> Solver<'a> for Day16 {"));
+        let year_mod = fs::read_to_string(root.join("src/years/y2022/mod.rs")).unwrap();
+        assert!(year_mod.contains("pub mod day15;\npub mod day16;\npub mod day2;\n"));
+        assert!(year_mod.contains("        .register_bytes::<day1::Day1>(YEAR, 1)\n"));
+        assert!(year_mod.contains(
+            "        .register::<day15::Day15>(YEAR, 15)\n        .register::<day16::Day16>(YEAR, 16);\n    registry\n"
+        ));
+        assert_eq!(
+            fs::read_to_string(root.join("input/2022/day16.txt")).unwrap(),
+            "input"
+        );
+
+        let err = new_day(root, 2022, 16).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::AlreadyExists);
+        let err = new_day(root, 2022, 3).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::AlreadyExists);
+
+        new_day(root, 2023, 1).unwrap();
+        new_day(root, 2021, 2).unwrap();
+        assert_eq!(
+            fs::read_to_string(root.join("src/years/mod.rs")).unwrap(),
+            "use crate::registry::Registry;\n\n\
+             pub mod y2021;\npub mod y2022;\npub mod y2023;\n\n\
+             #[must_use]\npub fn registry() -> Registry {\n    let mut registry = Registry::new();\n    \
+             y2021::register(&mut registry);\n    y2022::register(&mut registry);\n    \
+             y2023::register(&mut registry);\n    registry\n}\n"
+        );
+        assert_eq!(
+            fs::read_to_string(root.join("src/years/y2023/mod.rs")).unwrap(),
+            "use crate::registry::Registry;\n\npub mod day1;\n\npub const YEAR: u16 = 2023;\n\n\
+             pub fn register(registry: &mut Registry) {\n    registry.register::<day1::Day1>(YEAR, 1);\n}\n"
+        );
+    }
+}
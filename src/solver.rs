@@ -1,11 +1,171 @@
-use std::fmt::Debug;
+use std::{
+    fmt::{Debug, Display},
+    marker::PhantomData,
+};
+
+use crate::{error::Result, util::bytes::utf8};
 
 pub trait Solver<'a> {
-    type Parsed: Clone;
-    type Output: Debug + PartialEq;
-    type Output2: Debug + PartialEq = Self::Output;
+    type Parsed;
+    type Output: Debug + Display + PartialEq;
+    type Output2: Debug + Display + PartialEq = Self::Output;
+
+    fn parse(input: &'a str) -> Result<Self::Parsed>;
+    fn part1(data: &Self::Parsed) -> Result<Self::Output>;
+    fn part2(data: &Self::Parsed) -> Result<Self::Output2>;
+
+    /// Consuming form of [`Solver::part1`], used when the parsed data is not needed afterwards.
+    /// Parts that mutate the data can override it to skip the copy the borrowing form makes.
+    fn part1_owned(data: Self::Parsed) -> Result<Self::Output> {
+        Self::part1(&data)
+    }
+
+    fn part2_owned(data: Self::Parsed) -> Result<Self::Output2> {
+        Self::part2(&data)
+    }
+
+    /// Both answers from one shared computation, for days whose parts repeat work. Days without
+    /// one return `None`.
+    fn solve_both(_data: &Self::Parsed) -> Option<Result<(Self::Output, Self::Output2)>> {
+        None
+    }
+}
+
+/// A day that also parses its input straight from raw bytes, without the UTF-8 validation a `str`
+/// takes. Registered with [`Registry::register_bytes`](crate::registry::Registry::register_bytes).
+pub trait BytesSolver<'a>: Solver<'a> {
+    fn parse_bytes(input: &'a [u8]) -> Result<Self::Parsed>;
+}
+
+/// Another way to solve the parts of the day `S`, from the data its parser returns. Variants are
+/// registered under a name next to the day, see [`Registry::variant`](crate::registry::Registry::variant).
+pub trait Variant<'a, S: Solver<'a>> {
+    fn part1(data: &S::Parsed) -> Result<S::Output>;
+    fn part2(data: &S::Parsed) -> Result<S::Output2>;
+}
+
+/// Object-safe counterpart of [`Solver`], with the parsed data and the answers type-erased.
+pub trait DynSolver: Send + Sync {
+    fn parse<'a>(&self, input: &'a str) -> Result<Box<dyn DynParsed<'a> + 'a>>;
+
+    /// Parses a raw input, which solvers without a [`BytesSolver`] parser validate as UTF-8 first.
+    fn parse_bytes<'a>(&self, input: &'a [u8]) -> Result<Box<dyn DynParsed<'a> + 'a>> {
+        self.parse(utf8(input)?)
+    }
+
+    /// Whether [`DynSolver::parse_bytes`] has a parser of its own.
+    fn parses_bytes(&self) -> bool {
+        false
+    }
+}
+
+pub trait DynParsed<'a> {
+    fn part1(&self) -> Result<String>;
+    fn part2(&self) -> Result<String>;
+    fn into_part1(self: Box<Self>) -> Result<String>;
+    fn into_part2(self: Box<Self>) -> Result<String>;
+    fn solve_both(&self) -> Option<Result<[String; 2]>>;
+}
+
+struct Erased<S>(PhantomData<fn() -> S>);
+
+struct Parsed<'a, S: Solver<'a>>(S::Parsed);
+
+impl<S: for<'a> Solver<'a> + 'static> DynSolver for Erased<S> {
+    fn parse<'a>(&self, input: &'a str) -> Result<Box<dyn DynParsed<'a> + 'a>> {
+        S::parse(input).map(|parsed| Box::new(Parsed::<S>(parsed)) as Box<_>)
+    }
+}
+
+impl<'a, S: Solver<'a> + 'a> DynParsed<'a> for Parsed<'a, S> {
+    fn part1(&self) -> Result<String> {
+        S::part1(&self.0).map(|output| output.to_string())
+    }
+
+    fn part2(&self) -> Result<String> {
+        S::part2(&self.0).map(|output| output.to_string())
+    }
+
+    fn into_part1(self: Box<Self>) -> Result<String> {
+        S::part1_owned(self.0).map(|output| output.to_string())
+    }
+
+    fn into_part2(self: Box<Self>) -> Result<String> {
+        S::part2_owned(self.0).map(|output| output.to_string())
+    }
+
+    fn solve_both(&self) -> Option<Result<[String; 2]>> {
+        S::solve_both(&self.0).map(|both| both.map(|(a, b)| [a.to_string(), b.to_string()]))
+    }
+}
+
+#[must_use]
+pub fn erase<S: for<'a> Solver<'a> + 'static>() -> Box<dyn DynSolver> {
+    Box::new(Erased::<S>(PhantomData))
+}
+
+struct ErasedBytes<S>(PhantomData<fn() -> S>);
+
+impl<S: for<'a> BytesSolver<'a> + 'static> DynSolver for ErasedBytes<S> {
+    fn parse<'a>(&self, input: &'a str) -> Result<Box<dyn DynParsed<'a> + 'a>> {
+        S::parse(input).map(|parsed| Box::new(Parsed::<S>(parsed)) as Box<_>)
+    }
+
+    fn parse_bytes<'a>(&self, input: &'a [u8]) -> Result<Box<dyn DynParsed<'a> + 'a>> {
+        S::parse_bytes(input).map(|parsed| Box::new(Parsed::<S>(parsed)) as Box<_>)
+    }
+
+    fn parses_bytes(&self) -> bool {
+        true
+    }
+}
+
+#[must_use]
+pub fn erase_bytes<S: for<'a> BytesSolver<'a> + 'static>() -> Box<dyn DynSolver> {
+    Box::new(ErasedBytes::<S>(PhantomData))
+}
+
+struct ErasedVariant<S, V>(PhantomData<fn() -> (S, V)>);
+
+struct ParsedVariant<'a, S: Solver<'a>, V>(S::Parsed, PhantomData<fn() -> V>);
+
+impl<S, V> DynSolver for ErasedVariant<S, V>
+where
+    S: for<'a> Solver<'a> + 'static,
+    V: for<'a> Variant<'a, S> + 'static,
+{
+    fn parse<'a>(&self, input: &'a str) -> Result<Box<dyn DynParsed<'a> + 'a>> {
+        S::parse(input).map(|parsed| Box::new(ParsedVariant::<S, V>(parsed, PhantomData)) as Box<_>)
+    }
+}
+
+impl<'a, S: Solver<'a> + 'a, V: Variant<'a, S> + 'a> DynParsed<'a> for ParsedVariant<'a, S, V> {
+    fn part1(&self) -> Result<String> {
+        V::part1(&self.0).map(|output| output.to_string())
+    }
+
+    fn part2(&self) -> Result<String> {
+        V::part2(&self.0).map(|output| output.to_string())
+    }
+
+    fn into_part1(self: Box<Self>) -> Result<String> {
+        self.part1()
+    }
+
+    fn into_part2(self: Box<Self>) -> Result<String> {
+        self.part2()
+    }
+
+    fn solve_both(&self) -> Option<Result<[String; 2]>> {
+        None
+    }
+}
 
-    fn parse(input: &'a str) -> Self::Parsed;
-    fn part1(data: Self::Parsed) -> Self::Output;
-    fn part2(data: Self::Parsed) -> Self::Output2;
+#[must_use]
+pub fn erase_variant<S, V>() -> Box<dyn DynSolver>
+where
+    S: for<'a> Solver<'a> + 'static,
+    V: for<'a> Variant<'a, S> + 'static,
+{
+    Box::new(ErasedVariant::<S, V>(PhantomData))
 }
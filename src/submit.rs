@@ -0,0 +1,361 @@
+use std::{
+    fmt::Display,
+    io::{Error, ErrorKind, Result, Write},
+    path::Path,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::fetch::{status_error, Fetcher};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Outcome {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+    RateLimited,
+    AlreadySolved,
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Correct => "right answer",
+            Self::Wrong => "wrong answer",
+            Self::TooHigh => "wrong answer, too high",
+            Self::TooLow => "wrong answer, too low",
+            Self::RateLimited => "answered too recently",
+            Self::AlreadySolved => "already solved",
+        })
+    }
+}
+
+/// Reads the outcome of a submission, and how long the server wants us to wait before the next
+/// one, from the page it answered with.
+fn parse_reply(body: &str) -> Option<(Outcome, Duration)> {
+    let outcome = if body.contains("That's the right answer") {
+        Outcome::Correct
+    } else if body.contains("That's not the right answer") {
+        if body.contains("too high") {
+            Outcome::TooHigh
+        } else if body.contains("too low") {
+            Outcome::TooLow
+        } else {
+            Outcome::Wrong
+        }
+    } else if body.contains("You gave an answer too recently") {
+        Outcome::RateLimited
+    } else if body.contains("Did you already complete it?") {
+        Outcome::AlreadySolved
+    } else {
+        return None;
+    };
+
+    let left = Regex::new(r"You have (?:(\d+)m ?)?(?:(\d+)s )?left to wait").unwrap();
+    let again = Regex::new(r"(?i)wait (one|\d+) minutes? before trying again").unwrap();
+    let secs = left.captures(body).map_or_else(
+        || {
+            again
+                .captures(body)
+                .map_or(0, |captures| captures[1].parse::<u64>().unwrap_or(1) * 60)
+        },
+        |captures| {
+            let number = |i| {
+                captures
+                    .get(i)
+                    .map_or(0, |m| m.as_str().parse::<u64>().unwrap())
+            };
+            number(1) * 60 + number(2)
+        },
+    );
+    Some((outcome, Duration::from_secs(secs)))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Attempt {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub outcome: Outcome,
+    /// Unix time of the submission, in seconds.
+    pub time: u64,
+    /// Seconds the server asked to wait before submitting again.
+    #[serde(default)]
+    pub wait: u64,
+}
+
+/// Every answer submitted so far, stored as JSON lines in the order they were sent.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Attempts {
+    pub entries: Vec<Attempt>,
+}
+
+impl Attempts {
+    pub const PATH: &'static str = "submissions.jsonl";
+
+    /// Loads the attempts stored at `path`, with a missing file holding none.
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = match std::fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(Self::default()),
+            Err(e) => return Err(e),
+        };
+        let entries = content
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                serde_json::from_str(line).map_err(|e| Error::new(ErrorKind::InvalidData, e))
+            })
+            .collect::<Result<_>>()?;
+        Ok(Self { entries })
+    }
+
+    pub fn append(path: &Path, attempt: &Attempt) -> Result<()> {
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)?;
+        writeln!(file, "{}", serde_json::to_string(attempt)?)
+    }
+
+    /// The outcome that submitting `answer` would have, if earlier attempts already tell: the
+    /// same answer was judged before, the right one is known, or it is past a too high or too
+    /// low bound.
+    #[must_use]
+    pub fn known(&self, year: u16, day: u8, part: u8, answer: &str) -> Option<Outcome> {
+        let attempts = self
+            .entries
+            .iter()
+            .filter(|a| a.year == year && a.day == day && a.part == part)
+            .filter(|a| !matches!(a.outcome, Outcome::RateLimited | Outcome::AlreadySolved))
+            .collect::<Vec<_>>();
+        if let Some(attempt) = attempts.iter().find(|a| a.answer == answer) {
+            return Some(attempt.outcome);
+        }
+        if attempts.iter().any(|a| a.outcome == Outcome::Correct) {
+            return Some(Outcome::Wrong);
+        }
+        let value = answer.parse::<i128>().ok()?;
+        let bound = |outcome, past: fn(i128, i128) -> bool| {
+            attempts
+                .iter()
+                .filter(|a| a.outcome == outcome)
+                .filter_map(|a| a.answer.parse::<i128>().ok())
+                .any(|bound| past(value, bound))
+                .then_some(outcome)
+        };
+        bound(Outcome::TooHigh, |value, bound| value >= bound)
+            .or_else(|| bound(Outcome::TooLow, |value, bound| value <= bound))
+    }
+
+    /// Unix time in seconds from which the server accepts answers for the day again.
+    #[must_use]
+    pub fn ready_at(&self, year: u16, day: u8) -> u64 {
+        self.entries
+            .iter()
+            .filter(|a| a.year == year && a.day == day)
+            .map(|a| a.time + a.wait)
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Submission {
+    pub outcome: Outcome,
+    /// Whether the outcome comes from earlier attempts, without asking the server.
+    pub known: bool,
+    pub wait: Duration,
+}
+
+/// Submits `answer` unless the attempts stored at `path` already tell its outcome or the server
+/// still wants us to wait, and stores the new attempt.
+pub fn submit(
+    fetcher: &Fetcher,
+    path: &Path,
+    year: u16,
+    day: u8,
+    part: u8,
+    answer: &str,
+) -> Result<Submission> {
+    let attempts = Attempts::load(path)?;
+    if let Some(outcome) = attempts.known(year, day, part, answer) {
+        return Ok(Submission {
+            outcome,
+            known: true,
+            wait: Duration::ZERO,
+        });
+    }
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs());
+    let ready_at = attempts.ready_at(year, day);
+    if now < ready_at {
+        return Err(Error::new(
+            ErrorKind::WouldBlock,
+            format!("wait {}s before submitting again", ready_at - now),
+        ));
+    }
+
+    let response = fetcher.post(
+        year,
+        &format!("/{year}/day/{day}/answer"),
+        &[("level", &part.to_string()), ("answer", answer)],
+    )?;
+    if response.status != 200 {
+        return Err(status_error(&response));
+    }
+    let (outcome, wait) = parse_reply(&response.body).ok_or_else(|| {
+        Error::new(
+            ErrorKind::InvalidData,
+            "could not make sense of the server's reply",
+        )
+    })?;
+    Attempts::append(
+        path,
+        &Attempt {
+            year,
+            day,
+            part,
+            answer: answer.to_string(),
+            outcome,
+            time: now,
+            wait: wait.as_secs(),
+        },
+    )?;
+    Ok(Submission {
+        outcome,
+        known: false,
+        wait,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::Ordering;
+
+    use super::*;
+    use crate::fetch::tests::Server;
+
+    const WRONG: &str = "<article><p>That's not the right answer; your answer is too high. \
+                         Please wait one minute before trying again.</p></article>";
+
+    fn answers(method: &str, url: &str, body: &str) -> (u16, String) {
+        let reply = match (method, url, body) {
+            ("POST", "/2022/day/1/answer", "level=1&answer=24000") => {
+                "<article><p>That's the right answer! You are one gold star closer.</p></article>"
+            }
+            ("POST", "/2022/day/1/answer", "level=2&answer=50000") => WRONG,
+            ("POST", "/2022/day/2/answer", _) => {
+                "<article><p>You gave an answer too recently. You have 1m 5s left to wait.</p></article>"
+            }
+            ("POST", "/2022/day/3/answer", _) => "<html>maintenance</html>",
+            _ => return (404, "Not Found".to_string()),
+        };
+        (200, reply.to_string())
+    }
+
+    #[test]
+    fn replies() {
+        assert_eq!(
+            parse_reply(WRONG),
+            Some((Outcome::TooHigh, Duration::from_mins(1)))
+        );
+        assert_eq!(
+            parse_reply("That's not the right answer. please wait 5 minutes before trying again."),
+            Some((Outcome::Wrong, Duration::from_mins(5)))
+        );
+        assert_eq!(
+            parse_reply("You gave an answer too recently. You have 34s left to wait."),
+            Some((Outcome::RateLimited, Duration::from_secs(34)))
+        );
+        assert_eq!(
+            parse_reply(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            ),
+            Some((Outcome::AlreadySolved, Duration::ZERO))
+        );
+        assert_eq!(parse_reply("<html></html>"), None);
+    }
+
+    #[test]
+    fn known_outcomes() {
+        let attempt = |part, answer: &str, outcome| Attempt {
+            year: 2022,
+            day: 1,
+            part,
+            answer: answer.to_string(),
+            outcome,
+            time: 100,
+            wait: 60,
+        };
+        let attempts = Attempts {
+            entries: vec![
+                attempt(1, "10", Outcome::TooLow),
+                attempt(1, "20", Outcome::TooHigh),
+                attempt(1, "15", Outcome::RateLimited),
+                attempt(2, "7", Outcome::Correct),
+            ],
+        };
+        assert_eq!(attempts.known(2022, 1, 1, "10"), Some(Outcome::TooLow));
+        assert_eq!(attempts.known(2022, 1, 1, "9"), Some(Outcome::TooLow));
+        assert_eq!(attempts.known(2022, 1, 1, "25"), Some(Outcome::TooHigh));
+        assert_eq!(attempts.known(2022, 1, 1, "15"), None);
+        assert_eq!(attempts.known(2022, 1, 1, "abc"), None);
+        assert_eq!(attempts.known(2022, 1, 2, "7"), Some(Outcome::Correct));
+        assert_eq!(attempts.known(2022, 1, 2, "8"), Some(Outcome::Wrong));
+        assert_eq!(attempts.known(2022, 2, 1, "10"), None);
+        assert_eq!(attempts.ready_at(2022, 1), 160);
+        assert_eq!(attempts.ready_at(2022, 2), 0);
+    }
+
+    #[test]
+    fn submissions() {
+        let root = tempfile::tempdir().unwrap();
+        let path = root.path().join(Attempts::PATH);
+        let server = Server::start(answers);
+        let fetcher = server.fetcher("secret", root.path());
+
+        let submission = submit(&fetcher, &path, 2022, 1, 1, "24000").unwrap();
+        assert_eq!(submission.outcome, Outcome::Correct);
+        assert!(!submission.known);
+        let submission = submit(&fetcher, &path, 2022, 1, 1, "24000").unwrap();
+        assert_eq!(submission.outcome, Outcome::Correct);
+        assert!(submission.known);
+        assert_eq!(server.requests.load(Ordering::SeqCst), 1);
+
+        let submission = submit(&fetcher, &path, 2022, 1, 2, "50000").unwrap();
+        assert_eq!(
+            (submission.outcome, submission.wait),
+            (Outcome::TooHigh, Duration::from_mins(1))
+        );
+        assert_eq!(
+            submit(&fetcher, &path, 2022, 1, 2, "60000")
+                .unwrap()
+                .outcome,
+            Outcome::TooHigh
+        );
+        let err = submit(&fetcher, &path, 2022, 1, 2, "40000").unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::WouldBlock);
+        assert_eq!(server.requests.load(Ordering::SeqCst), 2);
+
+        let submission = submit(&fetcher, &path, 2022, 2, 1, "1").unwrap();
+        assert_eq!(
+            (submission.outcome, submission.wait),
+            (Outcome::RateLimited, Duration::from_secs(65))
+        );
+        let err = submit(&fetcher, &path, 2022, 3, 1, "1").unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+        let err = submit(&fetcher, &path, 2022, 4, 1, "1").unwrap_err();
+        assert!(err.to_string().contains("404"));
+
+        let attempts = Attempts::load(&path).unwrap();
+        assert_eq!(attempts.entries.len(), 3);
+        assert_eq!(attempts.entries[1].answer, "50000");
+    }
+}
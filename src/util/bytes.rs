@@ -0,0 +1,140 @@
+//! Parsing of raw input bytes, for the days that skip UTF-8 validation. Integers are read with
+//! `lexical_core`, and errors are positioned like the ones of [`super::parse`].
+
+use lexical_core::FromLexical;
+
+use crate::error::{Error, Result};
+
+/// `input` as text, or an error at its first byte that isn't valid UTF-8.
+pub fn utf8(input: &[u8]) -> Result<&str> {
+    std::str::from_utf8(input)
+        .map_err(|err| Error::parse_bytes(input, &input[err.valid_up_to()..], "UTF-8 text"))
+}
+
+/// A cursor over an input, consuming it from the front.
+pub struct Scanner<'a> {
+    input: &'a [u8],
+    rest: &'a [u8],
+}
+
+impl<'a> Scanner<'a> {
+    #[must_use]
+    pub const fn new(input: &'a [u8]) -> Self {
+        Self { input, rest: input }
+    }
+
+    /// Whether only whitespace is left.
+    #[must_use]
+    pub fn is_done(&self) -> bool {
+        self.rest.iter().all(u8::is_ascii_whitespace)
+    }
+
+    /// An error at the current position.
+    pub fn error(&self, expected: impl Into<String>) -> Error {
+        Error::parse_bytes(self.input, self.rest, expected)
+    }
+
+    /// Consumes `byte` if it comes next.
+    pub const fn eat(&mut self, byte: u8) -> bool {
+        match self.rest.split_first() {
+            Some((&next, rest)) if next == byte => {
+                self.rest = rest;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Consumes `tag` if it comes next.
+    pub fn eat_tag(&mut self, tag: &[u8]) -> bool {
+        match self.rest.strip_prefix(tag) {
+            Some(rest) => {
+                self.rest = rest;
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn byte(&mut self, byte: u8, expected: &str) -> Result<()> {
+        if self.eat(byte) {
+            Ok(())
+        } else {
+            Err(self.error(expected))
+        }
+    }
+
+    /// Consumes the next byte as read by `read`, or fails at it if `read` rejects it.
+    pub fn map<T>(&mut self, expected: &str, read: impl FnOnce(u8) -> Option<T>) -> Result<T> {
+        let (value, rest) = self
+            .rest
+            .split_first()
+            .and_then(|(&next, rest)| Some((read(next)?, rest)))
+            .ok_or_else(|| self.error(expected))?;
+        self.rest = rest;
+        Ok(value)
+    }
+
+    /// An integer, with a sign only for signed types. `lexical_core` reads no digits at all as a
+    /// zero, which is rejected here.
+    pub fn integer<T: FromLexical>(&mut self, expected: &str) -> Result<T> {
+        let (value, len) = lexical_core::parse_partial(self.rest)
+            .ok()
+            .filter(|&(_, len)| len > 0 && self.rest[len - 1].is_ascii_digit())
+            .ok_or_else(|| self.error(expected))?;
+        self.rest = &self.rest[len..];
+        Ok(value)
+    }
+
+    /// Items on lines of their own up to the trailing whitespace, each read by `item`.
+    pub fn lines<T>(mut self, mut item: impl FnMut(&mut Self) -> Result<T>) -> Result<Vec<T>> {
+        let mut items = Vec::new();
+        loop {
+            items.push(item(&mut self)?);
+            if self.is_done() {
+                return Ok(items);
+            }
+            self.byte(b'\n', "a line break")?;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scan() {
+        let mut scanner = Scanner::new(b"12,-3\nx");
+        assert_eq!(scanner.integer::<u8>("a byte"), Ok(12));
+        assert!(!scanner.eat(b'-') && scanner.eat(b','));
+        assert_eq!(scanner.integer::<i8>("an integer"), Ok(-3));
+        assert_eq!(
+            scanner.integer::<u8>("a byte").unwrap_err().to_string(),
+            "line 1, column 6: expected a byte"
+        );
+        assert!(scanner.eat(b'\n'));
+        assert!(scanner
+            .map("a digit", |b| (b as char).to_digit(10))
+            .is_err());
+        assert_eq!(scanner.map("a letter", |b| Some(b as char)), Ok('x'));
+        assert!(scanner.is_done() && !scanner.eat_tag(b"x"));
+        assert!(Scanner::new(b"300").integer::<u8>("a byte").is_err());
+        assert!(Scanner::new(b"-3").integer::<u8>("a byte").is_err());
+        assert!(Scanner::new(b"-").integer::<i8>("an integer").is_err());
+    }
+
+    #[test]
+    fn lines() {
+        let numbers = |input| Scanner::new(input).lines(|s| s.integer::<u32>("a number"));
+        assert_eq!(numbers(b"1\n2\n3\n\n"), Ok(vec![1, 2, 3]));
+        assert_eq!(
+            numbers(b"1\n2 3").unwrap_err().to_string(),
+            "line 2, column 2: expected a line break"
+        );
+        assert_eq!(
+            utf8(b"1\n\xff").unwrap_err().to_string(),
+            "line 2, column 1: expected UTF-8 text"
+        );
+    }
+}
@@ -0,0 +1,30 @@
+//! Calendar dates of Unix timestamps, for the benchmark history and the puzzle unlock times.
+
+/// The UTC year, month and day of `unix_secs`, after Howard Hinnant's `civil_from_days`.
+#[must_use]
+#[allow(clippy::cast_possible_wrap)]
+pub const fn civil(unix_secs: u64) -> (i64, i64, i64) {
+    let z = (unix_secs / 86_400) as i64 + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + (month <= 2) as i64;
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn civil_dates() {
+        assert_eq!(civil(0), (1970, 1, 1));
+        assert_eq!(civil(951_782_400), (2000, 2, 29));
+        assert_eq!(civil(1_669_852_800), (2022, 12, 1));
+        assert_eq!(civil(1_669_852_799), (2022, 11, 30));
+    }
+}
@@ -0,0 +1,387 @@
+use std::{
+    fmt::Display,
+    ops::{Add, AddAssign, Index, IndexMut, Mul, Neg, Sub},
+};
+
+use super::HashMap;
+
+/// A position on a grid, with `y` growing downwards the way puzzle inputs are laid out.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Pos {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Pos {
+    pub const UP: Self = Self::new(0, -1);
+    pub const DOWN: Self = Self::new(0, 1);
+    pub const LEFT: Self = Self::new(-1, 0);
+    pub const RIGHT: Self = Self::new(1, 0);
+    /// The steps to the 4-neighbourhood.
+    pub const ORTHOGONAL: [Self; 4] = [Self::UP, Self::LEFT, Self::RIGHT, Self::DOWN];
+    /// The steps to the 8-neighbourhood, in reading order.
+    pub const ALL: [Self; 8] = [
+        Self::new(-1, -1),
+        Self::UP,
+        Self::new(1, -1),
+        Self::LEFT,
+        Self::RIGHT,
+        Self::new(-1, 1),
+        Self::DOWN,
+        Self::new(1, 1),
+    ];
+
+    #[must_use]
+    pub const fn new(x: i32, y: i32) -> Self {
+        Self { x, y }
+    }
+
+    #[must_use]
+    pub const fn manhattan(self, other: Self) -> u32 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// The position as unsigned coordinates, if neither is negative.
+    #[must_use]
+    pub fn coords(self) -> Option<(usize, usize)> {
+        Some((usize::try_from(self.x).ok()?, usize::try_from(self.y).ok()?))
+    }
+
+    pub fn neighbours4(self) -> impl Iterator<Item = Self> {
+        Self::ORTHOGONAL.into_iter().map(move |step| self + step)
+    }
+
+    pub fn neighbours8(self) -> impl Iterator<Item = Self> {
+        Self::ALL.into_iter().map(move |step| self + step)
+    }
+}
+
+impl Add for Pos {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl AddAssign for Pos {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Pos {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl Neg for Pos {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+impl Mul<i32> for Pos {
+    type Output = Self;
+
+    fn mul(self, rhs: i32) -> Self::Output {
+        Self::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl Display for Pos {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+/// Cells looked up by position, whichever way they are stored.
+pub trait Grid {
+    type Cell;
+
+    fn get(&self, pos: Pos) -> Option<&Self::Cell>;
+
+    fn contains(&self, pos: Pos) -> bool {
+        self.get(pos).is_some()
+    }
+
+    /// The cells up, left, right and down of `pos` that are on the grid.
+    fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = (Pos, &Self::Cell)> {
+        pos.neighbours4()
+            .filter_map(|pos| self.get(pos).map(|cell| (pos, cell)))
+    }
+
+    /// The cells around `pos` that are on the grid, diagonals included.
+    fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = (Pos, &Self::Cell)> {
+        pos.neighbours8()
+            .filter_map(|pos| self.get(pos).map(|cell| (pos, cell)))
+    }
+}
+
+/// A rectangle of cells stored row by row, with its top left corner at 0,0.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dense<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Dense<T> {
+    #[must_use]
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+}
+
+impl<T> Dense<T> {
+    /// A grid of `width` columns holding `cells` row by row.
+    #[must_use]
+    pub fn from_cells(width: usize, cells: Vec<T>) -> Self {
+        Self {
+            width,
+            height: cells.len() / width.max(1),
+            cells,
+        }
+    }
+
+    #[must_use]
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+
+    #[must_use]
+    pub const fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The flat index of `pos` in row order, if it is on the grid.
+    #[must_use]
+    pub fn index(&self, pos: Pos) -> Option<usize> {
+        let x = usize::try_from(pos.x).ok().filter(|&x| x < self.width)?;
+        let y = usize::try_from(pos.y).ok().filter(|&y| y < self.height)?;
+        Some(y * self.width + x)
+    }
+
+    /// The position of a flat index in row order.
+    ///
+    /// # Panics
+    ///
+    /// If the index is too large for a coordinate to fit in an `i32`.
+    #[must_use]
+    pub fn pos(&self, index: usize) -> Pos {
+        let width = self.width.max(1);
+        let coord = |n: usize| i32::try_from(n).expect("grid coordinates fit in an i32");
+        Pos::new(coord(index % width), coord(index / width))
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.index(pos).map(|i| &mut self.cells[i])
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Pos> + '_ {
+        (0..self.cells.len()).map(|i| self.pos(i))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(i, cell)| (self.pos(i), cell))
+    }
+
+    /// The cells from `pos` in steps of `step`, not counting `pos` itself, up to the edge.
+    pub fn ray(&self, pos: Pos, step: Pos) -> impl Iterator<Item = (Pos, &T)> {
+        (1..)
+            .map(move |n| pos + step * n)
+            .map_while(|pos| self.get(pos).map(|cell| (pos, cell)))
+    }
+
+    /// The position of the first cell in row order that matches `predicate`.
+    pub fn find(&self, predicate: impl Fn(&T) -> bool) -> Option<Pos> {
+        self.cells.iter().position(predicate).map(|i| self.pos(i))
+    }
+}
+
+impl<T> Grid for Dense<T> {
+    type Cell = T;
+
+    fn get(&self, pos: Pos) -> Option<&T> {
+        self.index(pos).map(|i| &self.cells[i])
+    }
+}
+
+impl<T> Index<Pos> for Dense<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &Self::Output {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{pos} is off the {}x{} grid", self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<Pos> for Dense<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut Self::Output {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{pos} is off the {width}x{height} grid"))
+    }
+}
+
+impl<T: Display> Display for Dense<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.cells.chunks(self.width.max(1)) {
+            row.iter().try_for_each(|cell| write!(f, "{cell}"))?;
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// Cells at scattered positions, for grids without fixed bounds.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sparse<T> {
+    cells: HashMap<Pos, T>,
+}
+
+impl<T> Default for Sparse<T> {
+    fn default() -> Self {
+        Self {
+            cells: HashMap::new(),
+        }
+    }
+}
+
+impl<T> Sparse<T> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, pos: Pos, cell: T) -> Option<T> {
+        self.cells.insert(pos, cell)
+    }
+
+    pub fn remove(&mut self, pos: Pos) -> Option<T> {
+        self.cells.remove(&pos)
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.cells.get_mut(&pos)
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.cells.iter().map(|(&pos, cell)| (pos, cell))
+    }
+
+    /// The top left and bottom right corners of the smallest rectangle holding every cell.
+    #[must_use]
+    pub fn bounds(&self) -> Option<(Pos, Pos)> {
+        let mut positions = self.cells.keys();
+        let first = *positions.next()?;
+        Some(positions.fold((first, first), |(min, max), pos| {
+            (
+                Pos::new(min.x.min(pos.x), min.y.min(pos.y)),
+                Pos::new(max.x.max(pos.x), max.y.max(pos.y)),
+            )
+        }))
+    }
+}
+
+impl<T> Grid for Sparse<T> {
+    type Cell = T;
+
+    fn get(&self, pos: Pos) -> Option<&T> {
+        self.cells.get(&pos)
+    }
+}
+
+impl<T> FromIterator<(Pos, T)> for Sparse<T> {
+    fn from_iter<I: IntoIterator<Item = (Pos, T)>>(iter: I) -> Self {
+        Self {
+            cells: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<T> Extend<(Pos, T)> for Sparse<T> {
+    fn extend<I: IntoIterator<Item = (Pos, T)>>(&mut self, iter: I) {
+        self.cells.extend(iter);
+    }
+}
+
+/// Draws the cells within the bounds, with `.` for empty positions.
+impl<T: Display> Display for Sparse<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Some((min, max)) = self.bounds() else {
+            return Ok(());
+        };
+        for y in min.y..=max.y {
+            for x in min.x..=max.x {
+                match self.get(Pos::new(x, y)) {
+                    Some(cell) => write!(f, "{cell}")?,
+                    None => write!(f, ".")?,
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dense() {
+        let grid = Dense::from_cells(3, (1..=6).collect());
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Pos::new(2, 1)], 6);
+        assert_eq!(grid.get(Pos::new(3, 0)), None);
+        assert_eq!(grid.index(Pos::new(1, 1)), Some(4));
+        assert_eq!(grid.pos(4), Pos::new(1, 1));
+        assert_eq!(grid.find(|&d| d == 3), Some(Pos::new(2, 0)));
+        let ray = grid.ray(Pos::new(0, 0), Pos::RIGHT).map(|(_, &d)| d);
+        assert_eq!(ray.collect::<Vec<_>>(), [2, 3]);
+        let around = grid.neighbours4(Pos::new(0, 0)).map(|(_, &d)| d);
+        assert_eq!(around.collect::<Vec<_>>(), [2, 4]);
+        assert_eq!(grid.neighbours8(Pos::new(1, 0)).count(), 5);
+        assert_eq!(grid.to_string(), "123\n456\n");
+        assert_eq!(Pos::new(2, 1).coords(), Some((2, 1)));
+        assert_eq!(Pos::new(2, -1).coords(), None);
+        let empty = Dense::new(0, 0, 0u8);
+        assert_eq!((empty.pos(0), empty.find(|_| true)), (Pos::new(0, 0), None));
+    }
+
+    #[test]
+    fn sparse() {
+        let mut grid = [(Pos::new(-1, 2), '#'), (Pos::new(1, 3), '#')]
+            .into_iter()
+            .collect::<Sparse<_>>();
+        grid.insert(Pos::new(0, 3), 'o');
+        assert_eq!(grid.bounds(), Some((Pos::new(-1, 2), Pos::new(1, 3))));
+        assert!(grid.contains(Pos::new(0, 3)));
+        assert_eq!(grid.neighbours8(Pos::new(0, 2)).count(), 3);
+        assert_eq!(grid.to_string(), "#..\n.o#\n");
+        assert_eq!(Sparse::<char>::new().to_string(), "");
+    }
+}
@@ -0,0 +1,255 @@
+use std::ops::RangeInclusive;
+
+/// Integer types that intervals can be made of.
+pub trait Bound: Copy + Ord {
+    const MIN: Self;
+    const MAX: Self;
+
+    fn succ(self) -> Option<Self>;
+    fn pred(self) -> Option<Self>;
+    /// The number of values from `start` to `end`, both included.
+    fn span(start: Self, end: Self) -> u64;
+}
+
+macro_rules! bound {
+    ($($t:ty),*) => {$(
+        impl Bound for $t {
+            const MIN: Self = <$t>::MIN;
+            const MAX: Self = <$t>::MAX;
+
+            fn succ(self) -> Option<Self> {
+                self.checked_add(1)
+            }
+
+            fn pred(self) -> Option<Self> {
+                self.checked_sub(1)
+            }
+
+            #[allow(clippy::cast_lossless, clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            fn span(start: Self, end: Self) -> u64 {
+                (end as i128 - start as i128 + 1) as u64
+            }
+        }
+    )*};
+}
+
+bound!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+/// A set of integers, kept as sorted inclusive ranges that neither overlap nor touch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Intervals<T> {
+    ranges: Vec<(T, T)>,
+}
+
+impl<T> Default for Intervals<T> {
+    fn default() -> Self {
+        Self { ranges: Vec::new() }
+    }
+}
+
+impl<T: Bound> Intervals<T> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sorts `ranges` and merges the ones that overlap or touch, dropping empty ones.
+    fn normalize(mut ranges: Vec<(T, T)>) -> Self {
+        ranges.retain(|(start, end)| start <= end);
+        ranges.sort_unstable();
+        let mut merged = Vec::<(T, T)>::with_capacity(ranges.len());
+        for (start, end) in ranges {
+            match merged.last_mut() {
+                Some(last) if last.1.succ().is_none_or(|next| start <= next) => {
+                    last.1 = last.1.max(end);
+                }
+                _ => merged.push((start, end)),
+            }
+        }
+        Self { ranges: merged }
+    }
+
+    pub fn ranges(&self) -> impl Iterator<Item = RangeInclusive<T>> + '_ {
+        self.ranges.iter().map(|&(start, end)| start..=end)
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// The number of values in the set.
+    #[must_use]
+    pub fn len(&self) -> u64 {
+        self.ranges
+            .iter()
+            .map(|&(start, end)| T::span(start, end))
+            .sum()
+    }
+
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        let mut ranges = std::mem::take(&mut self.ranges);
+        ranges.push(range.into_inner());
+        *self = Self::normalize(ranges);
+    }
+
+    #[must_use]
+    pub fn contains(&self, value: T) -> bool {
+        self.containing(value).is_some()
+    }
+
+    /// Whether every value of `range` is in the set, which an empty range always is.
+    #[must_use]
+    pub fn contains_range(&self, range: &RangeInclusive<T>) -> bool {
+        range.is_empty()
+            || self
+                .containing(*range.start())
+                .is_some_and(|(_, end)| *range.end() <= end)
+    }
+
+    /// Whether every range of `other` lies within one of the set, walking both lists once.
+    #[must_use]
+    pub fn is_superset(&self, other: &Self) -> bool {
+        let mut ranges = self.ranges.iter().peekable();
+        other.ranges.iter().all(|&(start, end)| {
+            while ranges.next_if(|&&(_, last)| last < start).is_some() {}
+            ranges
+                .peek()
+                .is_some_and(|&&(first, last)| first <= start && end <= last)
+        })
+    }
+
+    #[must_use]
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.overlapping(other).next().is_some()
+    }
+
+    #[must_use]
+    pub fn union(&self, other: &Self) -> Self {
+        Self::normalize([&self.ranges[..], &other.ranges].concat())
+    }
+
+    #[must_use]
+    pub fn intersection(&self, other: &Self) -> Self {
+        Self {
+            ranges: self.overlapping(other).collect(),
+        }
+    }
+
+    #[must_use]
+    pub fn difference(&self, other: &Self) -> Self {
+        self.intersection(&other.gaps(T::MIN..=T::MAX))
+    }
+
+    /// The values of `within` that are not in the set.
+    #[must_use]
+    pub fn gaps(&self, within: RangeInclusive<T>) -> Self {
+        let (min, max) = within.into_inner();
+        let mut gaps = Vec::new();
+        let mut from = Some(min);
+        for &(start, end) in &self.ranges {
+            let Some(gap) = from.filter(|&gap| gap <= max) else {
+                break;
+            };
+            if let Some(before) = start.pred().filter(|&before| gap <= before) {
+                gaps.push((gap, before.min(max)));
+            }
+            from = end.succ().map(|after| after.max(gap));
+        }
+        gaps.extend(from.filter(|&gap| gap <= max).map(|gap| (gap, max)));
+        Self { ranges: gaps }
+    }
+
+    /// The ranges where the two sets overlap, in order, found by stepping through both lists
+    /// together.
+    fn overlapping<'a>(&'a self, other: &'a Self) -> impl Iterator<Item = (T, T)> + 'a {
+        let (mut a, mut b) = (
+            self.ranges.iter().peekable(),
+            other.ranges.iter().peekable(),
+        );
+        std::iter::from_fn(move || {
+            while let (Some(&&(a_start, a_end)), Some(&&(b_start, b_end))) = (a.peek(), b.peek()) {
+                if a_end < b_end {
+                    a.next();
+                } else {
+                    b.next();
+                }
+                let (start, end) = (a_start.max(b_start), a_end.min(b_end));
+                if start <= end {
+                    return Some((start, end));
+                }
+            }
+            None
+        })
+    }
+
+    /// The range holding `value`, found by binary search.
+    fn containing(&self, value: T) -> Option<(T, T)> {
+        let i = self.ranges.partition_point(|&(_, end)| end < value);
+        self.ranges
+            .get(i)
+            .copied()
+            .filter(|&(start, _)| start <= value)
+    }
+}
+
+impl<T: Bound> From<RangeInclusive<T>> for Intervals<T> {
+    fn from(range: RangeInclusive<T>) -> Self {
+        Self::normalize(vec![range.into_inner()])
+    }
+}
+
+impl<T: Bound> FromIterator<RangeInclusive<T>> for Intervals<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(iter: I) -> Self {
+        Self::normalize(iter.into_iter().map(RangeInclusive::into_inner).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(ranges: &[RangeInclusive<i32>]) -> Intervals<i32> {
+        ranges.iter().cloned().collect()
+    }
+
+    #[test]
+    fn normalized() {
+        let merged = set(&[8..=9, 1..=3, 2..=5, 6..=6, RangeInclusive::new(12, 11)]);
+        assert_eq!(merged.ranges().collect::<Vec<_>>(), [1..=6, 8..=9]);
+        assert_eq!(merged.len(), 8);
+        assert!(merged.contains(6) && !merged.contains(7) && !merged.contains(12));
+        assert!(merged.contains_range(&(2..=5)) && !merged.contains_range(&(5..=8)));
+        let full = Intervals::from(u8::MIN..=u8::MAX);
+        assert_eq!(full.len(), 256);
+        assert_eq!(set(&[i32::MIN..=0, 1..=i32::MAX]).len(), 1 << 32);
+    }
+
+    #[test]
+    fn operations() {
+        let a = set(&[1..=5, 10..=15]);
+        let b = set(&[4..=11, 20..=20]);
+        assert_eq!(a.union(&b), set(&[1..=15, 20..=20]));
+        assert_eq!(a.intersection(&b), set(&[4..=5, 10..=11]));
+        assert_eq!(a.difference(&b), set(&[1..=3, 12..=15]));
+        assert_eq!(b.difference(&a), set(&[6..=9, 20..=20]));
+        assert!(a.overlaps(&b) && !a.overlaps(&set(&[6..=9])));
+        assert!(a.is_superset(&set(&[2..=2, 4..=5, 11..=15])) && !a.is_superset(&b));
+        assert!(!a.is_superset(&set(&[2..=3, 16..=16])) && a.is_superset(&Intervals::new()));
+        assert!(!Intervals::new().overlaps(&a) && !a.overlaps(&set(&[0..=0, 16..=20])));
+        let mut a = a;
+        a.insert(6..=9);
+        assert_eq!(a, set(&[1..=15]));
+    }
+
+    #[test]
+    fn gaps() {
+        let a = set(&[1..=5, 10..=15]);
+        assert_eq!(a.gaps(0..=20), set(&[0..=0, 6..=9, 16..=20]));
+        assert_eq!(a.gaps(3..=12), set(&[6..=9]));
+        assert_eq!(a.gaps(11..=14), set(&[]));
+        assert_eq!(a.gaps(7..=8), set(&[7..=8]));
+        assert_eq!(Intervals::new().gaps(0..=3), set(&[0..=3]));
+        assert_eq!(set(&[i32::MIN..=i32::MAX]).gaps(0..=3), set(&[]));
+    }
+}
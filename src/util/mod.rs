@@ -1,19 +1,13 @@
 #![allow(dead_code)]
 
+pub mod bytes;
+pub mod date;
+pub mod grid;
+pub mod intervals;
+#[cfg(test)]
+pub mod oracle;
+pub mod parse;
+pub mod search;
+
 pub type HashMap<K, V> = ahash::AHashMap<K, V>;
 pub type HashSet<V> = ahash::AHashSet<V>;
-
-pub trait BStrParse {
-    fn parse<F: lexical_core::FromLexical>(&self) -> F;
-}
-
-impl BStrParse for [u8] {
-    fn parse<F: lexical_core::FromLexical>(&self) -> F {
-        lexical_core::parse(self).unwrap()
-    }
-}
-
-#[allow(clippy::trivially_copy_pass_by_ref)]
-pub const fn bytelines(&x: &u8) -> bool {
-    x == b'\n'
-}
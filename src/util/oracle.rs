@@ -0,0 +1,49 @@
+use std::fmt::Display;
+
+use proptest::{
+    prelude::*,
+    test_runner::{Config, TestCaseError, TestRunner},
+};
+
+use crate::solver::Solver;
+
+/// Checks that both parts of `S` give the same answers as the brute-force `part1` and `part2` on
+/// `cases` inputs drawn from `input`, with `None` standing for no solution. A disagreement fails
+/// with the smallest input that still shows it.
+pub fn agree<S, A, B>(
+    cases: u32,
+    input: impl Strategy<Value = String>,
+    part1: impl Fn(&str) -> Option<A>,
+    part2: impl Fn(&str) -> Option<B>,
+) where
+    S: for<'a> Solver<'a>,
+    A: Display,
+    B: Display,
+{
+    let mut runner = TestRunner::new(Config::with_cases(cases));
+    let result = runner.run(&input, |input| {
+        let parsed = S::parse(&input).map_err(|err| TestCaseError::fail(err.to_string()))?;
+        prop_assert_eq!(
+            S::part1(&parsed).ok().map(|answer| answer.to_string()),
+            part1(&input).map(|answer| answer.to_string()),
+            "part 1"
+        );
+        prop_assert_eq!(
+            S::part2(&parsed).ok().map(|answer| answer.to_string()),
+            part2(&input).map(|answer| answer.to_string()),
+            "part 2"
+        );
+        Ok(())
+    });
+    if let Err(err) = result {
+        panic!("{err}");
+    }
+}
+
+/// Lines of text joined with newlines, the way inputs are laid out.
+pub fn lines(lines: impl IntoIterator<Item = impl Display>) -> String {
+    lines
+        .into_iter()
+        .map(|line| line.to_string() + "\n")
+        .collect()
+}
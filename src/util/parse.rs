@@ -0,0 +1,326 @@
+//! `nom` combinators for the shapes puzzle inputs come in, with errors that keep the position of
+//! the innermost failure and what was expected there.
+
+use std::{borrow::Cow, str::FromStr};
+
+use nom::{
+    bytes::complete::tag,
+    character::complete::{char, digit1, multispace0, space0},
+    combinator::{all_consuming, map_res, opt, recognize},
+    error::{ErrorKind, FromExternalError},
+    sequence::{pair, preceded, separated_pair, terminated, tuple},
+    Parser,
+};
+
+use super::grid::Dense;
+use crate::error::{Error, Result};
+
+pub type IResult<'a, O> = nom::IResult<&'a str, O, ParseError<'a>>;
+
+/// Where parsing failed, and what was expected there if a parser said so.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError<'a> {
+    at: &'a str,
+    expected: Option<Cow<'static, str>>,
+}
+
+impl<'a> ParseError<'a> {
+    pub fn new(at: &'a str, expected: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            at,
+            expected: Some(expected.into()),
+        }
+    }
+}
+
+impl<'a> nom::error::ParseError<&'a str> for ParseError<'a> {
+    fn from_error_kind(input: &'a str, _kind: ErrorKind) -> Self {
+        Self {
+            at: input,
+            expected: None,
+        }
+    }
+
+    fn append(_input: &'a str, _kind: ErrorKind, other: Self) -> Self {
+        other
+    }
+}
+
+impl<'a, E> FromExternalError<&'a str, E> for ParseError<'a> {
+    fn from_external_error(input: &'a str, kind: ErrorKind, _e: E) -> Self {
+        nom::error::ParseError::from_error_kind(input, kind)
+    }
+}
+
+/// Runs `parser` over the whole of `input` but trailing whitespace. An error points at the
+/// innermost failure that says what it expected, or at where parsing stopped, and falls back
+/// to `expected`.
+pub fn complete<'a, O>(
+    input: &'a str,
+    parser: impl Parser<&'a str, O, ParseError<'a>>,
+    expected: &str,
+) -> Result<O> {
+    match all_consuming(terminated(parser, multispace0))(input) {
+        Ok((_, parsed)) => Ok(parsed),
+        Err(nom::Err::Error(err) | nom::Err::Failure(err)) => Err(Error::parse(
+            input,
+            err.at,
+            err.expected.as_deref().unwrap_or(expected),
+        )),
+        Err(nom::Err::Incomplete(_)) => Err(Error::parse(input, &input[input.len()..], expected)),
+    }
+}
+
+/// Labels the failures of `parser` that don't say what they expected yet with `expected`,
+/// pointing at where `parser` started.
+pub fn expect<'a, O>(
+    expected: impl Into<Cow<'static, str>>,
+    mut parser: impl Parser<&'a str, O, ParseError<'a>>,
+) -> impl FnMut(&'a str) -> IResult<'a, O> {
+    let expected = expected.into();
+    move |input| {
+        parser.parse(input).map_err(|err| {
+            err.map(|err| match err.expected {
+                Some(_) => err,
+                None => ParseError::new(input, expected.clone()),
+            })
+        })
+    }
+}
+
+pub fn unsigned<T: FromStr>(input: &str) -> IResult<'_, T> {
+    map_res(digit1, str::parse)(input)
+}
+
+pub fn signed<T: FromStr>(input: &str) -> IResult<'_, T> {
+    map_res(recognize(pair(opt(char('-')), digit1)), str::parse)(input)
+}
+
+/// Items separated by `separator`. Unlike `separated_list1`, an item that fails after a
+/// separator fails the list, so that its error isn't lost.
+pub fn list<'a, O>(
+    separator: &'static str,
+    mut item: impl Parser<&'a str, O, ParseError<'a>>,
+) -> impl FnMut(&'a str) -> IResult<'a, Vec<O>> {
+    move |mut input| {
+        let mut items = Vec::new();
+        loop {
+            let (rest, parsed) = item.parse(input)?;
+            items.push(parsed);
+            match rest.strip_prefix(separator) {
+                Some(next) => input = next,
+                None => return Ok((rest, items)),
+            }
+        }
+    }
+}
+
+/// Items on lines of their own, up to a blank line or the end of the input.
+pub fn lines<'a, O>(
+    item: impl Parser<&'a str, O, ParseError<'a>>,
+) -> impl FnMut(&'a str) -> IResult<'a, Vec<O>> {
+    separated_until_blank("\n", item)
+}
+
+/// Blocks of lines separated by blank lines.
+pub fn blocks<'a, O>(
+    block: impl Parser<&'a str, O, ParseError<'a>>,
+) -> impl FnMut(&'a str) -> IResult<'a, Vec<O>> {
+    separated_until_blank("\n\n", block)
+}
+
+/// Like `list`, but stops before a separator that isn't followed by more input, or by another
+/// line break.
+fn separated_until_blank<'a, O>(
+    separator: &'static str,
+    mut item: impl Parser<&'a str, O, ParseError<'a>>,
+) -> impl FnMut(&'a str) -> IResult<'a, Vec<O>> {
+    move |mut input| {
+        let mut items = Vec::new();
+        loop {
+            let (rest, parsed) = item.parse(input)?;
+            items.push(parsed);
+            match rest.strip_prefix(separator) {
+                Some(next) if !next.is_empty() && !next.starts_with('\n') => input = next,
+                _ => return Ok((rest, items)),
+            }
+        }
+    }
+}
+
+/// An `x=<n>, y=<n>` pair of coordinates.
+pub fn xy<T: FromStr>(input: &str) -> IResult<'_, (T, T)> {
+    separated_pair(
+        preceded(tag("x="), expect("an x coordinate", signed)),
+        tag(", "),
+        preceded(tag("y="), expect("a y coordinate", signed)),
+    )(input)
+}
+
+/// A `key: value` line of a record, indented or not.
+pub fn field<'a, O>(
+    key: &'static str,
+    value: impl Parser<&'a str, O, ParseError<'a>>,
+) -> impl FnMut(&'a str) -> IResult<'a, O> {
+    preceded(
+        tuple((
+            space0,
+            expect(format!("`{key}:`"), tag(key)),
+            char(':'),
+            space0,
+        )),
+        value,
+    )
+}
+
+/// A grid of one cell per character, with rows of `what` on lines of as many characters. A short or
+/// long row fails at its start, and a character `cell` rejects at itself, as not being `expected`.
+pub fn grid<'a, T>(
+    what: &'static str,
+    expected: &'static str,
+    cell: impl Fn(char) -> Option<T>,
+) -> impl FnMut(&'a str) -> IResult<'a, Dense<T>> {
+    move |input: &'a str| {
+        let row = |line: &'a str| line.split('\n').next().unwrap_or(line);
+        let width = row(input).chars().count();
+        if width == 0 {
+            return Err(nom::Err::Error(ParseError::new(
+                input,
+                format!("a row of {what}"),
+            )));
+        }
+        let mut cells = Vec::with_capacity(input.len());
+        let mut rest = input;
+        loop {
+            let line = row(rest);
+            if line.chars().count() != width {
+                return Err(nom::Err::Failure(ParseError::new(
+                    line,
+                    format!("a row of {width} {what}"),
+                )));
+            }
+            for (x, c) in line.char_indices() {
+                let parsed = cell(c)
+                    .ok_or_else(|| nom::Err::Failure(ParseError::new(&line[x..], expected)))?;
+                cells.push(parsed);
+            }
+            rest = &rest[line.len()..];
+            match rest.strip_prefix('\n') {
+                Some(next) if !next.is_empty() && !next.starts_with('\n') => rest = next,
+                _ => return Ok((rest, Dense::from_cells(width, cells))),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nom::character::complete::alpha1;
+
+    use super::*;
+
+    fn error<O: std::fmt::Debug>(result: Result<O>) -> String {
+        result.unwrap_err().to_string()
+    }
+
+    #[test]
+    fn integers() {
+        assert_eq!(
+            complete("1, -2, 3", list(", ", signed::<i8>), "numbers"),
+            Ok(vec![1, -2, 3])
+        );
+        assert_eq!(
+            error(complete("1, -2", list(", ", unsigned::<u8>), "numbers")),
+            "line 1, column 4: expected numbers"
+        );
+        assert_eq!(
+            error(complete(
+                "1\n300\n",
+                lines(expect("a byte", unsigned::<u8>)),
+                "bytes"
+            )),
+            "line 2, column 1: expected a byte"
+        );
+    }
+
+    #[test]
+    fn layout() {
+        let input = "ab\ncd\n\nef\n";
+        assert_eq!(
+            complete(input, blocks(lines(alpha1)), "words"),
+            Ok(vec![vec!["ab", "cd"], vec!["ef"]])
+        );
+        assert_eq!(
+            error(complete("ab\n\n1\n", blocks(lines(alpha1)), "words")),
+            "line 3, column 1: expected words"
+        );
+    }
+
+    #[test]
+    fn records() {
+        let record = pair(
+            terminated(field("Position", xy::<i32>), char('\n')),
+            field("Name", alpha1),
+        );
+        assert_eq!(
+            complete("Position: x=3, y=-4\n  Name: abc", record, "a record"),
+            Ok(((3, -4), "abc"))
+        );
+        let record = pair(
+            terminated(field("Position", xy::<i32>), char('\n')),
+            field("Name", alpha1),
+        );
+        assert_eq!(
+            error(complete(
+                "Position: x=3, y=z\nName: abc",
+                record,
+                "a record"
+            )),
+            "line 1, column 18: expected a y coordinate"
+        );
+        assert_eq!(
+            error(complete("Nome: abc", field("Name", alpha1), "a record")),
+            "line 1, column 1: expected `Name:`"
+        );
+    }
+
+    #[test]
+    fn grids() {
+        let digits = || grid("digits", "a digit", |c| c.to_digit(10));
+        let parsed = complete("123\n456\n", digits(), "a grid").unwrap();
+        assert_eq!((parsed.width(), parsed.height()), (3, 2));
+        assert_eq!(
+            error(complete("123\n45\n", digits(), "a grid")),
+            "line 2, column 1: expected a row of 3 digits"
+        );
+        assert_eq!(
+            error(complete("123\n4x6\n", digits(), "a grid")),
+            "line 2, column 2: expected a digit"
+        );
+        assert_eq!(
+            error(complete("", digits(), "a grid")),
+            "line 1, column 1: expected a row of digits"
+        );
+
+        let arrows = || grid("arrows", "an arrow", |c| "←↑→↓".contains(c).then_some(c));
+        let parsed = complete("←↑\n→↓\n", arrows(), "a grid").unwrap();
+        assert_eq!((parsed.width(), parsed.height()), (2, 2));
+        assert_eq!(parsed.to_string(), "←↑\n→↓\n");
+        assert_eq!(
+            error(complete("←↑\n→x\n", arrows(), "a grid")),
+            "line 2, column 2: expected an arrow"
+        );
+        assert_eq!(
+            error(complete("←↑↓\n→↓\n", arrows(), "a grid")),
+            "line 2, column 1: expected a row of 3 arrows"
+        );
+        assert_eq!(
+            error(complete("12\n3é\n", digits(), "a grid")),
+            "line 2, column 2: expected a digit"
+        );
+        assert_eq!(
+            error(complete("123\n4é\n", digits(), "a grid")),
+            "line 2, column 1: expected a row of 3 digits"
+        );
+    }
+}
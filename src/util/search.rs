@@ -0,0 +1,310 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, VecDeque},
+    hash::Hash,
+};
+
+use super::HashMap;
+
+/// A graph searched from a set of start nodes to the first goal node reached.
+pub trait Graph {
+    type Node: Copy + Eq + Hash;
+
+    fn neighbours(&self, node: Self::Node) -> impl Iterator<Item = Self::Node>;
+
+    /// The cost of the edge from `from` to its neighbour `to`, ignored by breadth-first search.
+    fn cost(&self, _from: Self::Node, _to: Self::Node) -> u32 {
+        1
+    }
+
+    /// A lower bound of the cost from `node` to the nearest goal, used by A*.
+    fn heuristic(&self, _node: Self::Node) -> u32 {
+        0
+    }
+
+    fn is_goal(&self, node: Self::Node) -> bool;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Strategy {
+    /// Fewest edges first, for graphs where every edge costs the same.
+    Bfs,
+    /// Cheapest first.
+    Dijkstra,
+    /// Cheapest first counting the heuristic, which expands fewer nodes when it is informative.
+    AStar,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    /// Nodes taken off the frontier, the goal included.
+    pub expanded: usize,
+    /// Distinct nodes reached, expanded or not.
+    pub discovered: usize,
+    /// The most nodes waiting on the frontier at once.
+    pub max_frontier: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<N> {
+    /// The nodes from a start to the goal, both included.
+    pub nodes: Vec<N>,
+    pub cost: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Outcome<N> {
+    pub path: Option<Path<N>>,
+    pub stats: Stats,
+}
+
+/// The state of a search after each expansion, for watching it.
+pub struct Progress<'a, N> {
+    nodes: &'a [Entry<N>],
+}
+
+impl<N: Copy> Progress<'_, N> {
+    pub fn expanded(&self) -> impl Iterator<Item = N> + '_ {
+        self.nodes.iter().filter(|n| n.expanded).map(|n| n.node)
+    }
+
+    /// The nodes reached but not expanded yet.
+    pub fn frontier(&self) -> impl Iterator<Item = N> + '_ {
+        self.nodes.iter().filter(|n| !n.expanded).map(|n| n.node)
+    }
+}
+
+struct Entry<N> {
+    node: N,
+    cost: u32,
+    parent: Option<usize>,
+    expanded: bool,
+}
+
+enum Frontier {
+    Queue(VecDeque<usize>),
+    Heap(BinaryHeap<Reverse<(u32, usize)>>),
+}
+
+impl Frontier {
+    fn push(&mut self, priority: u32, index: usize) {
+        match self {
+            Self::Queue(queue) => queue.push_back(index),
+            Self::Heap(heap) => heap.push(Reverse((priority, index))),
+        }
+    }
+
+    fn pop(&mut self) -> Option<usize> {
+        match self {
+            Self::Queue(queue) => queue.pop_front(),
+            Self::Heap(heap) => heap.pop().map(|Reverse((_, index))| index),
+        }
+    }
+
+    fn len(&self) -> usize {
+        match self {
+            Self::Queue(queue) => queue.len(),
+            Self::Heap(heap) => heap.len(),
+        }
+    }
+}
+
+pub fn bfs<G: Graph>(graph: &G, from: impl IntoIterator<Item = G::Node>) -> Outcome<G::Node> {
+    search(graph, from, Strategy::Bfs, |_| {})
+}
+
+pub fn dijkstra<G: Graph>(graph: &G, from: impl IntoIterator<Item = G::Node>) -> Outcome<G::Node> {
+    search(graph, from, Strategy::Dijkstra, |_| {})
+}
+
+pub fn astar<G: Graph>(graph: &G, from: impl IntoIterator<Item = G::Node>) -> Outcome<G::Node> {
+    search(graph, from, Strategy::AStar, |_| {})
+}
+
+/// Searches `graph` from the nodes in `from` until a goal is expanded, calling `observe` after each
+/// expansion. Nodes are kept in a flat list with the index of their parent, which the path is
+/// read back from.
+pub fn search<G: Graph>(
+    graph: &G,
+    from: impl IntoIterator<Item = G::Node>,
+    strategy: Strategy,
+    mut observe: impl FnMut(&Progress<G::Node>),
+) -> Outcome<G::Node> {
+    let priority = |node, cost| match strategy {
+        Strategy::AStar => cost + graph.heuristic(node),
+        Strategy::Bfs | Strategy::Dijkstra => cost,
+    };
+    let mut nodes = Vec::<Entry<G::Node>>::new();
+    let mut indices = HashMap::new();
+    let mut frontier = if strategy == Strategy::Bfs {
+        Frontier::Queue(VecDeque::new())
+    } else {
+        Frontier::Heap(BinaryHeap::new())
+    };
+    for start in from {
+        indices.entry(start).or_insert_with(|| {
+            frontier.push(priority(start, 0), nodes.len());
+            nodes.push(Entry {
+                node: start,
+                cost: 0,
+                parent: None,
+                expanded: false,
+            });
+            nodes.len() - 1
+        });
+    }
+    let mut stats = Stats {
+        max_frontier: frontier.len(),
+        ..Stats::default()
+    };
+    while let Some(current) = frontier.pop() {
+        if nodes[current].expanded {
+            continue;
+        }
+        nodes[current].expanded = true;
+        stats.expanded += 1;
+        observe(&Progress { nodes: &nodes });
+        let Entry { node, cost, .. } = nodes[current];
+        if graph.is_goal(node) {
+            stats.discovered = nodes.len();
+            let path = path(&nodes, current);
+            return Outcome {
+                path: Some(Path { nodes: path, cost }),
+                stats,
+            };
+        }
+        for next in graph.neighbours(node) {
+            let cost = cost
+                + match strategy {
+                    Strategy::Bfs => 1,
+                    Strategy::Dijkstra | Strategy::AStar => graph.cost(node, next),
+                };
+            let index = *indices.entry(next).or_insert_with(|| {
+                nodes.push(Entry {
+                    node: next,
+                    cost: u32::MAX,
+                    parent: None,
+                    expanded: false,
+                });
+                nodes.len() - 1
+            });
+            let known = &mut nodes[index];
+            if known.expanded || known.cost <= cost {
+                continue;
+            }
+            known.cost = cost;
+            known.parent = Some(current);
+            frontier.push(priority(next, cost), index);
+        }
+        stats.max_frontier = stats.max_frontier.max(frontier.len());
+    }
+    stats.discovered = nodes.len();
+    Outcome { path: None, stats }
+}
+
+fn path<N: Copy>(nodes: &[Entry<N>], goal: usize) -> Vec<N> {
+    let mut path = std::iter::successors(Some(goal), |&i| nodes[i].parent)
+        .map(|i| nodes[i].node)
+        .collect::<Vec<_>>();
+    path.reverse();
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::{
+        grid::{Dense, Grid, Pos},
+        parse,
+    };
+
+    /// A maze of walls `#` and squares costing their digit to enter, from the top left to the
+    /// top right corner.
+    struct Maze(Dense<char>);
+
+    impl Maze {
+        fn new(rows: &str) -> Self {
+            Self(parse::complete(rows, parse::grid("squares", "a square", Some), "a maze").unwrap())
+        }
+
+        fn goal(&self) -> Pos {
+            self.0.pos(self.0.width() - 1)
+        }
+    }
+
+    impl Graph for Maze {
+        type Node = Pos;
+
+        fn neighbours(&self, pos: Pos) -> impl Iterator<Item = Pos> {
+            self.0
+                .neighbours4(pos)
+                .filter(|&(_, &c)| c != '#')
+                .map(|(pos, _)| pos)
+        }
+
+        fn cost(&self, _from: Pos, to: Pos) -> u32 {
+            self.0[to].to_digit(10).unwrap()
+        }
+
+        fn heuristic(&self, pos: Pos) -> u32 {
+            pos.manhattan(self.goal())
+        }
+
+        fn is_goal(&self, pos: Pos) -> bool {
+            pos == self.goal()
+        }
+    }
+
+    const MAZE: &str = "\
+191
+1#1
+111
+";
+
+    #[test]
+    fn strategies() {
+        let maze = Maze::new(MAZE);
+        let start = [Pos::new(0, 0)];
+        let fewest = bfs(&maze, start).path.unwrap();
+        assert_eq!(
+            fewest.nodes,
+            [Pos::new(0, 0), Pos::new(1, 0), Pos::new(2, 0)]
+        );
+        assert_eq!(fewest.cost, 2);
+        let cheapest = dijkstra(&maze, start).path.unwrap();
+        assert_eq!(cheapest.cost, 6);
+        assert_eq!(cheapest.nodes.len(), 7);
+        assert_eq!(cheapest.nodes.first(), Some(&Pos::new(0, 0)));
+        assert_eq!(cheapest.nodes.last(), Some(&maze.goal()));
+        assert!(!cheapest.nodes.contains(&Pos::new(1, 0)));
+        assert_eq!(astar(&maze, start).path, Some(cheapest));
+    }
+
+    #[test]
+    fn heuristic_expands_less() {
+        let maze = Maze::new(&"11111111\n".repeat(8));
+        let start = [Pos::new(0, 0)];
+        let plain = dijkstra(&maze, start);
+        let guided = astar(&maze, start);
+        assert_eq!(plain.path.unwrap().cost, 7);
+        assert_eq!(guided.path.unwrap().cost, 7);
+        assert_eq!(guided.stats.expanded, 8);
+        assert!(plain.stats.expanded > 28);
+        assert!(guided.stats.discovered < plain.stats.discovered);
+    }
+
+    #[test]
+    fn unreachable() {
+        let maze = Maze::new("1#1\n");
+        let outcome = bfs(&maze, [Pos::new(0, 0), Pos::new(0, 0)]);
+        assert_eq!(outcome.path, None);
+        assert_eq!(
+            outcome.stats,
+            Stats {
+                expanded: 1,
+                discovered: 1,
+                max_frontier: 1
+            }
+        );
+    }
+}
@@ -0,0 +1,332 @@
+use std::{
+    fmt::{Display, Write as _},
+    fs::File,
+    io::{self, BufWriter, Write},
+    marker::PhantomData,
+    path::PathBuf,
+    thread,
+    time::Duration,
+};
+
+use crate::{
+    error::Result,
+    solver::Solver,
+    util::{bytes::utf8, grid::Pos},
+};
+
+/// A picture of a simulation step, as a grid of characters that back-ends draw as text or pixels.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    width: usize,
+    height: usize,
+    cells: Vec<char>,
+}
+
+impl Frame {
+    #[must_use]
+    pub fn new(width: usize, height: usize, fill: char) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    #[must_use]
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+
+    #[must_use]
+    pub const fn height(&self) -> usize {
+        self.height
+    }
+
+    #[must_use]
+    pub fn get(&self, x: usize, y: usize) -> Option<char> {
+        (x < self.width && y < self.height).then(|| self.cells[y * self.width + x])
+    }
+
+    /// Sets a cell, ignoring positions outside the frame.
+    pub fn set(&mut self, x: usize, y: usize, c: char) {
+        if x < self.width && y < self.height {
+            self.cells[y * self.width + x] = c;
+        }
+    }
+
+    /// The cell at a grid position, if it's in the frame.
+    pub(crate) fn get_pos(&self, pos: Pos) -> Option<char> {
+        pos.coords().and_then(|(x, y)| self.get(x, y))
+    }
+
+    /// Sets the cell at a grid position, ignoring positions outside the frame.
+    pub(crate) fn set_pos(&mut self, pos: Pos, c: char) {
+        if let Some((x, y)) = pos.coords() {
+            self.set(x, y, c);
+        }
+    }
+
+    fn rows(&self) -> impl Iterator<Item = &[char]> {
+        self.cells.chunks(self.width.max(1))
+    }
+}
+
+impl Display for Frame {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            writeln!(f, "{}", row.iter().collect::<String>())?;
+        }
+        Ok(())
+    }
+}
+
+/// The color every back-end draws a cell with: walls and rocks in gray, sand in yellow, letters
+/// from green to brown in alphabetical order and upper case letters in red.
+#[must_use]
+pub const fn color(c: char) -> [u8; 3] {
+    match c {
+        ' ' => [0, 0, 0],
+        '.' => [40, 40, 48],
+        '#' => [150, 150, 150],
+        'o' | '*' => [230, 200, 90],
+        '+' => [230, 60, 60],
+        'a'..='z' => {
+            let t = (c as u8 - b'a') * 6;
+            [60 + t, 160 - t / 2, 60]
+        }
+        'A'..='Z' => [220, 60, 60],
+        _ => [230, 230, 230],
+    }
+}
+
+pub trait Backend {
+    fn draw(&mut self, frame: &Frame) -> io::Result<()>;
+}
+
+/// Animates frames in a terminal that understands ANSI escape codes, in place.
+pub struct Terminal<W> {
+    pub out: W,
+    pub delay: Duration,
+}
+
+impl<W: Write> Backend for Terminal<W> {
+    fn draw(&mut self, frame: &Frame) -> io::Result<()> {
+        let mut text = String::from("\x1b[H\x1b[2J");
+        for row in frame.rows() {
+            let mut last = None;
+            for &c in row {
+                if last != Some(color(c)) {
+                    let [r, g, b] = color(c);
+                    let _ = write!(text, "\x1b[38;2;{r};{g};{b}m");
+                    last = Some([r, g, b]);
+                }
+                text.push(c);
+            }
+            text += "\x1b[0m\n";
+        }
+        self.out.write_all(text.as_bytes())?;
+        self.out.flush()?;
+        thread::sleep(self.delay);
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageFormat {
+    Ppm,
+    Png,
+}
+
+/// Writes frames as numbered images, `frame00000.png` and so on, with each cell `scale` pixels
+/// wide.
+pub struct Images {
+    pub dir: PathBuf,
+    pub format: ImageFormat,
+    pub scale: usize,
+    pub count: usize,
+}
+
+impl Images {
+    #[must_use]
+    pub const fn new(dir: PathBuf, format: ImageFormat, scale: usize) -> Self {
+        Self {
+            dir,
+            format,
+            scale,
+            count: 0,
+        }
+    }
+
+    fn pixels(&self, frame: &Frame) -> Vec<u8> {
+        let scale = self.scale.max(1);
+        let mut pixels = Vec::with_capacity(frame.cells.len() * scale * scale * 3);
+        for row in frame.rows() {
+            let line = row
+                .iter()
+                .flat_map(|&c| std::iter::repeat_n(color(c), scale).flatten())
+                .collect::<Vec<_>>();
+            for _ in 0..scale {
+                pixels.extend(&line);
+            }
+        }
+        pixels
+    }
+}
+
+impl Backend for Images {
+    fn draw(&mut self, frame: &Frame) -> io::Result<()> {
+        std::fs::create_dir_all(&self.dir)?;
+        let extension = match self.format {
+            ImageFormat::Ppm => "ppm",
+            ImageFormat::Png => "png",
+        };
+        let path = self.dir.join(format!("frame{:05}.{extension}", self.count));
+        let (width, height) = (
+            frame.width * self.scale.max(1),
+            frame.height * self.scale.max(1),
+        );
+        let pixels = self.pixels(frame);
+        let mut out = BufWriter::new(File::create(path)?);
+        match self.format {
+            ImageFormat::Ppm => {
+                write!(out, "P6\n{width} {height}\n255\n")?;
+                out.write_all(&pixels)?;
+            }
+            ImageFormat::Png => {
+                let size = |n: usize| u32::try_from(n).map_err(io::Error::other);
+                let mut encoder = png::Encoder::new(&mut out, size(width)?, size(height)?);
+                encoder.set_color(png::ColorType::Rgb);
+                encoder.set_depth(png::BitDepth::Eight);
+                encoder
+                    .write_header()
+                    .and_then(|mut writer| writer.write_image_data(&pixels))
+                    .map_err(io::Error::other)?;
+            }
+        }
+        out.flush()?;
+        self.count += 1;
+        Ok(())
+    }
+}
+
+/// Hands the frames of a visualization to a back-end, drawing one step out of every `every`.
+pub struct Frames<'a> {
+    backend: &'a mut dyn Backend,
+    every: usize,
+    steps: usize,
+    drawn: usize,
+    error: Option<io::Error>,
+}
+
+impl<'a> Frames<'a> {
+    pub fn new(backend: &'a mut dyn Backend, every: usize) -> Self {
+        Self {
+            backend,
+            every: every.max(1),
+            steps: 0,
+            drawn: 0,
+            error: None,
+        }
+    }
+
+    /// A step of the simulation, which is only drawn when its turn comes.
+    pub fn step(&mut self, draw: impl FnOnce() -> Frame) {
+        self.steps += 1;
+        if (self.steps - 1).is_multiple_of(self.every) {
+            self.draw(&draw());
+        }
+    }
+
+    /// The final state, which is always drawn.
+    pub fn end(&mut self, draw: impl FnOnce() -> Frame) {
+        self.draw(&draw());
+    }
+
+    fn draw(&mut self, frame: &Frame) {
+        if self.error.is_none() {
+            match self.backend.draw(frame) {
+                Ok(()) => self.drawn += 1,
+                Err(err) => self.error = Some(err),
+            }
+        }
+    }
+
+    /// The number of frames drawn, or the first error of the back-end.
+    pub fn finish(self) -> io::Result<usize> {
+        self.error.map_or(Ok(self.drawn), Err)
+    }
+}
+
+/// Days that can show how they solve a part, frame by frame.
+pub trait Visualize<'a>: Solver<'a> {
+    fn visualize(data: &Self::Parsed, part: u8, frames: &mut Frames) -> Result<()>;
+}
+
+/// Object-safe counterpart of [`Visualize`], parsing the raw input first.
+pub trait DynVisualize: Send + Sync {
+    fn visualize(&self, input: &[u8], part: u8, frames: &mut Frames) -> Result<()>;
+}
+
+struct Erased<S>(PhantomData<fn() -> S>);
+
+impl<S: for<'a> Visualize<'a> + 'static> DynVisualize for Erased<S> {
+    fn visualize(&self, input: &[u8], part: u8, frames: &mut Frames) -> Result<()> {
+        S::visualize(&S::parse(utf8(input)?)?, part, frames)
+    }
+}
+
+#[must_use]
+pub fn erase<S: for<'a> Visualize<'a> + 'static>() -> Box<dyn DynVisualize> {
+    Box::new(Erased::<S>(PhantomData))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Default)]
+    struct Recorder(Vec<Frame>);
+
+    impl Backend for Recorder {
+        fn draw(&mut self, frame: &Frame) -> io::Result<()> {
+            self.0.push(frame.clone());
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn sampled_frames() {
+        let mut recorder = Recorder::default();
+        let mut frames = Frames::new(&mut recorder, 3);
+        for i in 0..7 {
+            frames.step(|| Frame::new(i, 1, '.'));
+        }
+        frames.end(|| Frame::new(9, 1, '#'));
+        assert_eq!(frames.finish().unwrap(), 4);
+        let widths = recorder.0.iter().map(Frame::width).collect::<Vec<_>>();
+        assert_eq!(widths, [0, 3, 6, 9]);
+    }
+
+    #[test]
+    fn images() {
+        let mut frame = Frame::new(2, 1, '.');
+        frame.set(1, 0, '#');
+        frame.set(5, 5, '#');
+        assert_eq!(frame.to_string(), ".#\n");
+
+        let dir = tempfile::tempdir().unwrap();
+        let mut ppm = Images::new(dir.path().to_path_buf(), ImageFormat::Ppm, 2);
+        ppm.draw(&frame).unwrap();
+        let image = std::fs::read(dir.path().join("frame00000.ppm")).unwrap();
+        let header = b"P6\n4 2\n255\n";
+        assert_eq!(&image[..header.len()], header);
+        let row = [color('.'), color('.'), color('#'), color('#')].concat();
+        assert_eq!(&image[header.len()..], [row.clone(), row].concat());
+
+        let mut png = Images::new(dir.path().to_path_buf(), ImageFormat::Png, 1);
+        png.draw(&frame).unwrap();
+        png.draw(&frame).unwrap();
+        let image = std::fs::read(dir.path().join("frame00001.png")).unwrap();
+        assert_eq!(&image[1..4], b"PNG");
+    }
+}
@@ -0,0 +1,10 @@
+use crate::registry::Registry;
+
+pub mod y2022;
+
+#[must_use]
+pub fn registry() -> Registry {
+    let mut registry = Registry::new();
+    y2022::register(&mut registry);
+    registry
+}
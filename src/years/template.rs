@@ -0,0 +1,24 @@
+use crate::{
+    error::{Error, Result},
+    solver::Solver,
+    util::*,
+};
+
+pub struct DayNUM;
+
+impl<'a> Solver<'a> for DayNUM {
+    type Parsed = u32;
+    type Output = u32;
+
+    fn parse(input: &'a str) -> Result<Self::Parsed> {
+        todo!()
+    }
+
+    fn part1(data: &Self::Parsed) -> Result<Self::Output> {
+        todo!()
+    }
+
+    fn part2(data: &Self::Parsed) -> Result<Self::Output> {
+        todo!()
+    }
+}
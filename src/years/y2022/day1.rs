@@ -0,0 +1,122 @@
+use crate::{
+    error::{Error, Result},
+    solver::{BytesSolver, Solver, Variant},
+    util::{
+        bytes::Scanner,
+        parse::{blocks, complete, expect, lines, unsigned},
+    },
+};
+
+pub struct Day1;
+
+/// Picks the elves carrying the most with a partial sort instead of sorting them all.
+pub struct Select;
+
+#[derive(Debug, Clone)]
+pub struct Parsed {
+    elves: Vec<usize>,
+    calories: Vec<u32>,
+}
+
+impl<'a> Solver<'a> for Day1 {
+    type Parsed = Parsed;
+    type Output = u32;
+
+    fn parse(input: &'a str) -> Result<Self::Parsed> {
+        let calorie = || expect("a calorie count", unsigned::<u32>);
+        let elves = complete(input, blocks(lines(calorie())), "a calorie count")?;
+        let mut end = 0;
+        Ok(Parsed {
+            elves: elves
+                .iter()
+                .map(|elf| {
+                    end += elf.len();
+                    end
+                })
+                .collect(),
+            calories: elves.into_iter().flatten().collect(),
+        })
+    }
+
+    fn part1(data: &Self::Parsed) -> Result<Self::Output> {
+        totals(data)
+            .into_iter()
+            .max()
+            .ok_or_else(Error::no_solution)
+    }
+
+    fn part2(data: &Self::Parsed) -> Result<Self::Output> {
+        let mut calories = totals(data);
+        calories.sort_unstable();
+        calories.reverse();
+        calories
+            .get(..3)
+            .map(|top| top.iter().sum())
+            .ok_or_else(Error::no_solution)
+    }
+
+    fn solve_both(data: &Self::Parsed) -> Option<Result<(Self::Output, Self::Output)>> {
+        let mut calories = totals(data);
+        calories.sort_unstable_by(|a, b| b.cmp(a));
+        let most = calories.first().copied().ok_or_else(Error::no_solution);
+        let top_three = calories
+            .get(..3)
+            .map(|top| top.iter().sum())
+            .ok_or_else(Error::no_solution);
+        Some(most.and_then(|most| Ok((most, top_three?))))
+    }
+}
+
+impl<'a> BytesSolver<'a> for Day1 {
+    fn parse_bytes(input: &'a [u8]) -> Result<Self::Parsed> {
+        let mut scanner = Scanner::new(input);
+        let mut parsed = Parsed {
+            elves: Vec::new(),
+            calories: Vec::new(),
+        };
+        loop {
+            parsed.calories.push(scanner.integer("a calorie count")?);
+            if scanner.is_done() {
+                parsed.elves.push(parsed.calories.len());
+                return Ok(parsed);
+            }
+            scanner.byte(b'\n', "a line break")?;
+            if scanner.eat(b'\n') {
+                parsed.elves.push(parsed.calories.len());
+            }
+        }
+    }
+}
+
+impl Variant<'_, Day1> for Select {
+    fn part1(data: &Parsed) -> Result<u32> {
+        top(data, 1)
+    }
+
+    fn part2(data: &Parsed) -> Result<u32> {
+        top(data, 3)
+    }
+}
+
+/// The calories carried by each elf.
+fn totals(data: &Parsed) -> Vec<u32> {
+    let mut start = 0;
+    data.elves
+        .iter()
+        .map(|&end| {
+            let total = data.calories[start..end].iter().sum();
+            start = end;
+            total
+        })
+        .collect()
+}
+
+/// The calories carried by the `n` elves carrying the most.
+fn top(data: &Parsed, n: usize) -> Result<u32> {
+    let mut totals = totals(data);
+    if totals.len() < n {
+        return Err(Error::no_solution());
+    }
+    let (top, nth, _) = totals.select_nth_unstable_by(n - 1, |a, b| b.cmp(a));
+    Ok(top.iter().sum::<u32>() + *nth)
+}
@@ -0,0 +1,108 @@
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    combinator::{map, value},
+    sequence::preceded,
+};
+
+use crate::{
+    error::Result,
+    solver::{BytesSolver, Solver},
+    util::{
+        bytes::Scanner,
+        parse::{complete, expect, lines, signed},
+    },
+};
+
+pub struct Day10;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Command {
+    cycle: i32,
+    val: i32,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct State {
+    signal: i32,
+    pos: i32,
+    x: i32,
+}
+
+impl<'a> Solver<'a> for Day10 {
+    type Parsed = Vec<Command>;
+    type Output = i32;
+    type Output2 = String;
+
+    fn parse(input: &'a str) -> Result<Self::Parsed> {
+        let command = alt((
+            value(Command { cycle: 1, val: 0 }, tag("noop")),
+            map(
+                preceded(tag("addx "), expect("an integer", signed)),
+                |val| Command { cycle: 2, val },
+            ),
+        ));
+        complete(input, lines(command), "`noop` or `addx <n>`")
+    }
+
+    fn part1(data: &Self::Parsed) -> Result<Self::Output> {
+        let mut state = State {
+            signal: 20,
+            pos: 0,
+            x: 1,
+        };
+        Ok(data
+            .iter()
+            .fold(vec![], |mut signal_strengths, cmd| {
+                let new_pos = state.pos + cmd.cycle;
+                if new_pos >= state.signal {
+                    signal_strengths.push(state);
+                    state.signal += 40;
+                }
+                state.x += cmd.val;
+                state.pos = new_pos;
+                signal_strengths
+            })
+            .iter()
+            .map(|state| state.signal * state.x)
+            .sum())
+    }
+
+    fn part2(data: &Self::Parsed) -> Result<Self::Output2> {
+        let mut state = State {
+            signal: 20,
+            pos: 0,
+            x: 1,
+        };
+        Ok(data.iter().fold(String::new(), |mut crt, cmd| {
+            (0..cmd.cycle)
+                .map(|i| i + state.pos)
+                .map(|pos| pos % 40)
+                .map(|x| (x + 1, (x - state.x).abs() <= 1))
+                .for_each(|(pos, draw)| {
+                    crt.push(if draw { '#' } else { '.' });
+                    if pos % 40 == 0 {
+                        crt.push('\n');
+                    }
+                });
+            state.x += cmd.val;
+            state.pos += cmd.cycle;
+            crt
+        }))
+    }
+}
+
+impl<'a> BytesSolver<'a> for Day10 {
+    fn parse_bytes(input: &'a [u8]) -> Result<Self::Parsed> {
+        Scanner::new(input).lines(|scanner| {
+            if scanner.eat_tag(b"noop") {
+                Ok(Command { cycle: 1, val: 0 })
+            } else if scanner.eat_tag(b"addx ") {
+                let val = scanner.integer("an integer")?;
+                Ok(Command { cycle: 2, val })
+            } else {
+                Err(scanner.error("`noop` or `addx <n>`"))
+            }
+        })
+    }
+}
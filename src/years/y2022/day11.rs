@@ -0,0 +1,271 @@
+use std::collections::VecDeque;
+
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{char, digit1, newline},
+    combinator::{consumed, map, map_opt, value},
+    sequence::{delimited, preceded, terminated, tuple},
+};
+
+use crate::{
+    error::{Error, Result},
+    solver::Solver,
+    util::parse::{blocks, complete, expect, field, list, unsigned, IResult},
+};
+
+pub struct Day11;
+
+#[derive(Debug, Clone)]
+pub struct Monkey {
+    items: VecDeque<u64>,
+    op: Operation,
+    test: Test,
+    inspected: u64,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Operation(Exp, Op, Exp);
+
+impl Operation {
+    const fn execute(&self, old: u64) -> u64 {
+        let first = match self.0 {
+            Exp::Old => old,
+            Exp::Int(x) => x,
+        };
+        let second = match self.2 {
+            Exp::Old => old,
+            Exp::Int(x) => x,
+        };
+        match self.1 {
+            Op::Add => first + second,
+            Op::Mul => first * second,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Exp {
+    Old,
+    Int(u64),
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Op {
+    Add,
+    Mul,
+}
+
+fn operation(input: &str) -> IResult<'_, Operation> {
+    let exp = || {
+        expect(
+            "`old` or an integer",
+            alt((value(Exp::Old, tag("old")), map(unsigned, Exp::Int))),
+        )
+    };
+    let op = expect(
+        "`+` or `*`",
+        alt((value(Op::Add, char('+')), value(Op::Mul, char('*')))),
+    );
+    map(
+        tuple((exp(), delimited(char(' '), op, char(' ')), exp())),
+        |(first, op, second)| Operation(first, op, second),
+    )(input)
+}
+
+/// A monkey's notes, one `key: value` field per line under a `Monkey <n>:` header, with where its
+/// two throw targets are written, to check them once the number of monkeys is known.
+fn monkey(input: &str) -> IResult<'_, (Monkey, [&str; 2])> {
+    let throw = |key| {
+        field(
+            key,
+            preceded(
+                tag("throw to monkey "),
+                consumed(expect("a monkey number", unsigned)),
+            ),
+        )
+    };
+    let header = expect("`Monkey <n>:`", tuple((tag("Monkey "), digit1, char(':'))));
+    map(
+        tuple((
+            terminated(header, newline),
+            terminated(
+                field(
+                    "Starting items",
+                    list(", ", expect("a worry level", unsigned)),
+                ),
+                newline,
+            ),
+            terminated(
+                field("Operation", preceded(tag("new = "), operation)),
+                newline,
+            ),
+            terminated(
+                field(
+                    "Test",
+                    preceded(
+                        tag("divisible by "),
+                        expect(
+                            "a divisor other than 0",
+                            map_opt(unsigned, |divisor| (divisor > 0).then_some(divisor)),
+                        ),
+                    ),
+                ),
+                newline,
+            ),
+            terminated(throw("If true"), newline),
+            throw("If false"),
+        )),
+        |(_, items, op, divisible, (true_at, true_case), (false_at, false_case))| {
+            let monkey = Monkey {
+                items: items.into(),
+                op,
+                test: Test {
+                    divisible,
+                    true_case,
+                    false_case,
+                },
+                inspected: 0,
+            };
+            (monkey, [true_at, false_at])
+        },
+    )(input)
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Test {
+    divisible: u64,
+    true_case: usize,
+    false_case: usize,
+}
+
+impl Test {
+    const fn test(&self, worry: u64) -> usize {
+        if worry.is_multiple_of(self.divisible) {
+            self.true_case
+        } else {
+            self.false_case
+        }
+    }
+}
+
+fn round(monkeys: &mut [Monkey]) {
+    for i in 0..monkeys.len() {
+        let monkey = monkeys.get_mut(i).unwrap();
+        let throws = monkey
+            .items
+            .iter()
+            .map(|&item| monkey.op.execute(item) / 3)
+            .map(|worry| (monkey.test.test(worry), worry))
+            .collect::<Vec<_>>();
+        monkey.inspected += throws.len() as u64;
+        monkey.items.clear();
+        for (monkey, worry) in throws {
+            monkeys[monkey].items.push_back(worry);
+        }
+    }
+}
+
+fn round_2(monkeys: &mut [Monkey], prime_product: u64) {
+    for i in 0..monkeys.len() {
+        let monkey = monkeys.get_mut(i).unwrap();
+        let throws = monkey
+            .items
+            .iter()
+            .map(|&item| monkey.op.execute(item) % prime_product)
+            .map(|worry| (monkey.test.test(worry), worry))
+            .collect::<Vec<_>>();
+        monkey.inspected += throws.len() as u64;
+        monkey.items.clear();
+        for (monkey, worry) in throws {
+            monkeys[monkey].items.push_back(worry);
+        }
+    }
+}
+
+impl<'a> Solver<'a> for Day11 {
+    type Parsed = Vec<Monkey>;
+    type Output = u64;
+    type Output2 = u64;
+
+    fn parse(input: &'a str) -> Result<Self::Parsed> {
+        let notes = complete(input, blocks(monkey), "a monkey")?;
+        let last = notes.len() - 1;
+        notes
+            .into_iter()
+            .map(|(monkey, [true_at, false_at])| {
+                for (target, at) in [
+                    (monkey.test.true_case, true_at),
+                    (monkey.test.false_case, false_at),
+                ] {
+                    if target > last {
+                        return Err(Error::parse(
+                            input,
+                            at,
+                            format!("a monkey number from 0 to {last}"),
+                        ));
+                    }
+                }
+                Ok(monkey)
+            })
+            .collect()
+    }
+
+    fn part1(data: &Self::Parsed) -> Result<Self::Output> {
+        Self::part1_owned(data.clone())
+    }
+
+    fn part2(data: &Self::Parsed) -> Result<Self::Output2> {
+        Self::part2_owned(data.clone())
+    }
+
+    fn part1_owned(mut monkeys: Self::Parsed) -> Result<Self::Output> {
+        (0..20).for_each(|_| round(&mut monkeys));
+        let mut total_inspected = monkeys.iter().map(|m| m.inspected).collect::<Vec<_>>();
+        total_inspected.sort_unstable_by(|a, b| b.cmp(a));
+        total_inspected
+            .get(..2)
+            .map(|top| top.iter().product())
+            .ok_or_else(Error::no_solution)
+    }
+
+    fn part2_owned(mut monkeys: Self::Parsed) -> Result<Self::Output2> {
+        let prime_product: u64 = monkeys.iter().map(|monkey| monkey.test.divisible).product();
+        (0..10_000).for_each(|_| round_2(&mut monkeys, prime_product));
+        let mut total_inspected = monkeys.iter().map(|m| m.inspected).collect::<Vec<_>>();
+        total_inspected.sort_unstable_by(|a, b| b.cmp(a));
+        total_inspected
+            .get(..2)
+            .map(|top| top.iter().product())
+            .ok_or_else(Error::no_solution)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn notes(divisor: u64, true_case: usize, false_case: usize) -> String {
+        format!(
+            "Monkey 0:\n  Starting items: 79, 98\n  Operation: new = old * 19\n  \
+             Test: divisible by {divisor}\n    If true: throw to monkey {true_case}\n    \
+             If false: throw to monkey {false_case}"
+        )
+    }
+
+    #[test]
+    fn d11_impossible_monkeys() {
+        let error = |input: &str| Day11::parse(input).unwrap_err().to_string();
+        assert_eq!(
+            error(&notes(23, 0, 1)),
+            "line 6, column 31: expected a monkey number from 0 to 0"
+        );
+        assert_eq!(
+            error(&notes(0, 0, 0)),
+            "line 4, column 22: expected a divisor other than 0"
+        );
+        let monkeys = Day11::parse(&notes(23, 0, 0)).unwrap();
+        assert_eq!(Day11::part1(&monkeys), Err(Error::no_solution()));
+        assert_eq!(Day11::part2(&monkeys), Err(Error::no_solution()));
+    }
+}
@@ -0,0 +1,293 @@
+use std::fmt::Display;
+
+use crate::{
+    error::{Error, Result},
+    solver::Solver,
+    util::{
+        grid::{Dense, Grid, Pos},
+        parse,
+        search::{self, Graph, Outcome, Progress},
+    },
+    visualize::{Frame, Frames, Visualize},
+};
+
+pub struct Day12;
+
+#[derive(Debug, Clone)]
+pub struct Heightmap {
+    start: Pos,
+    end: Pos,
+    grid: Dense<Height>,
+}
+
+/// The heightmap as climbed in a part: up from `S` to `E` in part 1, or down from `E` to any
+/// lowest square in part 2.
+struct Climb<'a> {
+    map: &'a Heightmap,
+    part: u8,
+}
+
+impl Graph for Climb<'_> {
+    type Node = Pos;
+
+    fn neighbours(&self, pos: Pos) -> impl Iterator<Item = Pos> {
+        let here = self.map.grid[pos].value();
+        self.map
+            .grid
+            .neighbours4(pos)
+            .filter(move |(_, there)| {
+                if self.part == 1 {
+                    there.value() - here <= 1
+                } else {
+                    here - there.value() <= 1
+                }
+            })
+            .map(|(pos, _)| pos)
+    }
+
+    fn heuristic(&self, pos: Pos) -> u32 {
+        pos.manhattan(self.map.end)
+    }
+
+    fn is_goal(&self, pos: Pos) -> bool {
+        if self.part == 1 {
+            pos == self.map.end
+        } else {
+            self.map.grid[pos].value() == 0
+        }
+    }
+}
+
+impl Heightmap {
+    /// Searches with A* towards `E` in part 1, and breadth first in part 2 where there is no
+    /// single goal to aim for.
+    fn climb(&self, part: u8, observe: impl FnMut(&Progress<Pos>)) -> Outcome<Pos> {
+        let climb = Climb { map: self, part };
+        if part == 1 {
+            search::search(&climb, [self.start], search::Strategy::AStar, observe)
+        } else {
+            search::search(&climb, [self.end], search::Strategy::Bfs, observe)
+        }
+    }
+
+    fn fewest_steps(&self, part: u8) -> Result<u32> {
+        self.climb(part, |_| {})
+            .path
+            .map(|path| path.cost)
+            .ok_or_else(Error::no_solution)
+    }
+
+    fn frame(&self) -> Frame {
+        let mut frame = Frame::new(self.grid.width(), self.grid.height(), '.');
+        for (pos, h) in self.grid.iter() {
+            frame.set_pos(pos, h.0);
+        }
+        frame
+    }
+}
+
+impl Display for Heightmap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f)?;
+        write!(f, "{}", self.grid)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Height(char);
+
+impl Height {
+    const fn value(self) -> i16 {
+        let v = match self.0 {
+            'S' => 'a',
+            'E' => 'z',
+            x => x,
+        };
+        v as i16 - 'a' as i16
+    }
+
+    const fn is_start(self) -> bool {
+        self.0 == 'S'
+    }
+    const fn is_end(self) -> bool {
+        self.0 == 'E'
+    }
+}
+
+impl Display for Height {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl<'a> Solver<'a> for Day12 {
+    type Parsed = Heightmap;
+    type Output = u32;
+
+    fn parse(input: &'a str) -> Result<Self::Parsed> {
+        let heights = parse::grid("heights", "a height", |c| {
+            matches!(c, 'a'..='z' | 'S' | 'E').then_some(Height(c))
+        });
+        let grid = parse::complete(input, heights, "a row of heights")?;
+        let start = grid
+            .find(|h| h.is_start())
+            .ok_or_else(|| Error::parse(input, &input[input.len()..], "a start `S`"))?;
+        let end = grid
+            .find(|h| h.is_end())
+            .ok_or_else(|| Error::parse(input, &input[input.len()..], "an end `E`"))?;
+        Ok(Heightmap { start, end, grid })
+    }
+
+    fn part1(data: &Self::Parsed) -> Result<Self::Output> {
+        data.fewest_steps(1)
+    }
+
+    fn part2(data: &Self::Parsed) -> Result<Self::Output> {
+        data.fewest_steps(2)
+    }
+}
+
+impl Visualize<'_> for Day12 {
+    fn visualize(data: &Self::Parsed, part: u8, frames: &mut Frames) -> Result<()> {
+        let heights = data.frame();
+        let path = data
+            .climb(part, |progress| {
+                frames.step(|| {
+                    let mut frame = heights.clone();
+                    for pos in progress.expanded() {
+                        frame.set_pos(pos, '.');
+                    }
+                    for pos in progress.frontier() {
+                        frame.set_pos(pos, '*');
+                    }
+                    frame
+                });
+            })
+            .path
+            .ok_or_else(Error::no_solution)?;
+        frames.end(|| {
+            let mut frame = heights;
+            for &pos in &path.nodes {
+                if let Some(c) = frame.get_pos(pos) {
+                    frame.set_pos(pos, c.to_ascii_uppercase());
+                }
+            }
+            frame
+        });
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::util::oracle;
+
+    /// Heightmaps of `size / 2` to `size` squares a side that fall away from `E` one step at a time,
+    /// with some noise so that only some of them can be climbed. `S` is either anywhere or as far
+    /// from `E` as it gets.
+    fn heightmap(size: usize) -> impl Strategy<Value = String> {
+        let length = size / 2..=size;
+        (length.clone(), length).prop_flat_map(|(rows, cols)| {
+            let cells = rows * cols;
+            let noise = prop::collection::vec(-1..=1i32, cells);
+            (prop::option::of(0..cells), 0..cells, noise).prop_filter_map(
+                "`S` and `E` must differ",
+                move |(start, end, noise)| {
+                    let distance = |i: usize| {
+                        (i % cols).abs_diff(end % cols) + (i / cols).abs_diff(end / cols)
+                    };
+                    let start =
+                        start.unwrap_or_else(|| (0..cells).max_by_key(|&i| distance(i)).unwrap());
+                    let squares = (0..cells).map(|i| match i {
+                        _ if i == start => 'S',
+                        _ if i == end => 'E',
+                        _ => {
+                            let fall = i32::try_from(distance(i)).unwrap_or(25);
+                            let height = (25 - fall + noise[i]).clamp(0, 25);
+                            char::from(b'a' + u8::try_from(height).unwrap())
+                        }
+                    });
+                    let squares = squares.collect::<Vec<_>>();
+                    let rows = squares
+                        .chunks(cols)
+                        .map(|row| row.iter().collect::<String>());
+                    (start != end).then(|| oracle::lines(rows))
+                },
+            )
+        })
+    }
+
+    /// Breadth-first search from the squares marked `from` to the first one accepted by `goal`,
+    /// stepping to neighbours accepted by `step`.
+    fn fewest_steps(
+        input: &str,
+        from: u8,
+        goal: impl Fn(u8) -> bool,
+        step: impl Fn(i32, i32) -> bool,
+    ) -> Option<usize> {
+        let squares = input.lines().map(str::as_bytes).collect::<Vec<_>>();
+        let height = |square: u8| match square {
+            b'S' => 0,
+            b'E' => 25,
+            square => i32::from(square - b'a'),
+        };
+        let (rows, cols) = (squares.len(), squares[0].len());
+        let mut steps = vec![vec![None; cols]; rows];
+        let mut queue = VecDeque::new();
+        for (y, row) in squares.iter().enumerate() {
+            for (x, &square) in row.iter().enumerate() {
+                if square == from {
+                    steps[y][x] = Some(0);
+                    queue.push_back((x, y));
+                }
+            }
+        }
+        while let Some((x, y)) = queue.pop_front() {
+            let here = steps[y][x]?;
+            if goal(squares[y][x]) {
+                return Some(here);
+            }
+            let neighbours = [
+                (x.wrapping_sub(1), y),
+                (x + 1, y),
+                (x, y.wrapping_sub(1)),
+                (x, y + 1),
+            ];
+            for (nx, ny) in neighbours {
+                if nx < cols
+                    && ny < rows
+                    && steps[ny][nx].is_none()
+                    && step(height(squares[y][x]), height(squares[ny][nx]))
+                {
+                    steps[ny][nx] = Some(here + 1);
+                    queue.push_back((nx, ny));
+                }
+            }
+        }
+        None
+    }
+
+    fn reference1(input: &str) -> Option<usize> {
+        fewest_steps(
+            input,
+            b'S',
+            |square| square == b'E',
+            |from, to| to - from <= 1,
+        )
+    }
+
+    fn reference2(input: &str) -> Option<usize> {
+        let lowest = |square| square == b'a' || square == b'S';
+        fewest_steps(input, b'E', lowest, |from, to| from - to <= 1)
+    }
+
+    #[test]
+    fn matches_reference() {
+        oracle::agree::<Day12, _, _>(2000, heightmap(30), reference1, reference2);
+    }
+}
@@ -1,30 +1,33 @@
-use std::{collections::BTreeSet, fmt::Display};
+use std::fmt::Display;
 
 use nom::{
     branch::alt,
     bytes::complete::tag,
     character::complete::{newline, u8},
     combinator::map,
-    multi::{separated_list0, separated_list1},
+    multi::separated_list0,
     sequence::{delimited, separated_pair},
-    IResult,
 };
 
-use crate::{solver::Solver, util::*};
+use crate::{
+    error::Result,
+    solver::Solver,
+    util::parse::{blocks, complete, expect, IResult},
+};
 
 pub struct Day13;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Packet {
     Int(u8),
-    List(Vec<Packet>),
+    List(Vec<Self>),
 }
 
 impl Display for Packet {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            Packet::Int(i) => write!(f, "{i}"),
-            Packet::List(list) => {
+            Self::Int(i) => write!(f, "{i}"),
+            Self::List(list) => {
                 write!(f, "[")?;
                 let mut iter = list.iter();
                 if let Some(p) = iter.next() {
@@ -51,13 +54,13 @@ impl Ord for Packet {
         match (self, other) {
             (Self::Int(a), Self::Int(b)) => a.cmp(b),
             (Self::List(a), Self::List(b)) => a.cmp(b),
-            (a, Packet::List(b)) => [a.clone()][..].cmp(b),
-            (Packet::List(a), b) => a[..].cmp(&[b.clone()][..]),
+            (a, Self::List(b)) => [a.clone()][..].cmp(b),
+            (Self::List(a), b) => a[..].cmp(&[b.clone()][..]),
         }
     }
 }
 
-fn parse_packet<'a>(i: &'a str) -> IResult<&'a str, Packet> {
+fn parse_packet(i: &str) -> IResult<'_, Packet> {
     alt((
         map(u8, Packet::Int),
         map(
@@ -67,41 +70,36 @@ fn parse_packet<'a>(i: &'a str) -> IResult<&'a str, Packet> {
     ))(i)
 }
 
-fn parse_input<'a>(i: &'a str) -> IResult<&'a str, Vec<(Packet, Packet)>> {
-    separated_list1(
-        tag("\n\n"),
+fn parse_input(i: &str) -> IResult<'_, Vec<(Packet, Packet)>> {
+    blocks(expect(
+        "a pair of packets",
         separated_pair(parse_packet, newline, parse_packet),
-    )(i)
+    ))(i)
 }
 
 impl<'a> Solver<'a> for Day13 {
     type Parsed = Vec<(Packet, Packet)>;
     type Output = usize;
 
-    fn parse(input: &'a str) -> Self::Parsed {
-        parse_input(input).unwrap().1
+    fn parse(input: &'a str) -> Result<Self::Parsed> {
+        complete(input, parse_input, "a pair of packets")
     }
 
-    fn part1(data: Self::Parsed) -> Self::Output {
-        data.iter()
+    fn part1(data: &Self::Parsed) -> Result<Self::Output> {
+        Ok(data
+            .iter()
             .zip(1..)
             .filter(|((left, right), _)| left < right)
             .map(|(_, i)| i)
-            .sum()
+            .sum())
     }
 
-    fn part2(data: Self::Parsed) -> Self::Output {
-        let mut packets = data
-            .into_iter()
-            .flat_map(|pair| [pair.0, pair.1])
-            .collect::<BTreeSet<_>>();
+    fn part2(data: &Self::Parsed) -> Result<Self::Output> {
         let first = parse_packet("[[2]]").unwrap().1;
         let second = parse_packet("[[6]]").unwrap().1;
-        packets.insert(first.clone());
-        packets.insert(second.clone());
-        let first = packets.iter().position(|x| x == &first).unwrap() + 1;
-        let second = packets.iter().position(|x| x == &second).unwrap() + 1;
-        first * second
+        let packets = data.iter().flat_map(|(left, right)| [left, right]);
+        let before = |divider| packets.clone().filter(|&packet| packet < divider).count();
+        Ok((before(&first) + 1) * (before(&second) + 2))
     }
 }
 
@@ -109,7 +107,89 @@ impl<'a> Solver<'a> for Day13 {
 mod tests {
     use std::cmp::Ordering;
 
+    use proptest::prelude::*;
+    use serde_json::Value;
+
     use super::*;
+    use crate::util::oracle;
+
+    /// Packets nested up to `depth` lists deep, each list holding up to `width` values.
+    fn packet(depth: u32, width: usize) -> impl Strategy<Value = Packet> {
+        let leaf = (0..=10u8).prop_map(Packet::Int);
+        let nested = leaf.prop_recursive(depth, 64, u32::try_from(width).unwrap(), move |inner| {
+            prop::collection::vec(inner, 0..=width).prop_map(Packet::List)
+        });
+        prop::collection::vec(nested, 0..=width).prop_map(Packet::List)
+    }
+
+    /// Up to `pairs` pairs of packets.
+    fn packet_pairs(pairs: usize) -> impl Strategy<Value = String> {
+        let pair = (packet(3, 4), packet(3, 4));
+        prop::collection::vec(pair, 1..=pairs).prop_map(|pairs| {
+            pairs
+                .iter()
+                .map(|(left, right)| format!("{left}\n{right}\n"))
+                .collect::<Vec<_>>()
+                .join("\n")
+        })
+    }
+
+    /// The order of two packets, read as JSON, following the rules of the puzzle to the letter.
+    fn compare(left: &Value, right: &Value) -> Ordering {
+        match (left, right) {
+            (Value::Number(left), Value::Number(right)) => left.as_u64().cmp(&right.as_u64()),
+            (Value::Array(left), Value::Array(right)) => {
+                for (left, right) in left.iter().zip(right) {
+                    let order = compare(left, right);
+                    if order != Ordering::Equal {
+                        return order;
+                    }
+                }
+                left.len().cmp(&right.len())
+            }
+            (Value::Number(_), _) => compare(&Value::Array(vec![left.clone()]), right),
+            (_, Value::Number(_)) => compare(left, &Value::Array(vec![right.clone()])),
+            _ => unreachable!("packets only hold numbers and lists"),
+        }
+    }
+
+    fn packets(input: &str) -> Vec<Value> {
+        input
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect()
+    }
+
+    fn reference1(input: &str) -> usize {
+        let packets = packets(input);
+        let ordered = packets
+            .chunks(2)
+            .enumerate()
+            .filter(|(_, pair)| compare(&pair[0], &pair[1]) == Ordering::Less);
+        ordered.map(|(i, _)| i + 1).sum()
+    }
+
+    /// Sorts the packets after the divider packets, so that a divider comes before the packets
+    /// equal to it.
+    fn reference2(input: &str) -> Option<usize> {
+        let dividers = [serde_json::json!([[2]]), serde_json::json!([[6]])];
+        let mut packets = dividers.to_vec();
+        packets.extend(self::packets(input));
+        packets.sort_by(compare);
+        let position = |divider| packets.iter().position(|p| p == divider).map(|i| i + 1);
+        Some(position(&dividers[0])? * position(&dividers[1])?)
+    }
+
+    #[test]
+    fn matches_reference() {
+        oracle::agree::<Day13, _, _>(
+            2000,
+            packet_pairs(12),
+            |input| Some(reference1(input)),
+            reference2,
+        );
+    }
 
     #[test]
     fn ord_int() {
@@ -190,66 +270,10 @@ mod tests {
     }
 
     #[test]
-    fn d13p1() {
-        assert_eq!(
-            Day13::part1(Day13::parse(
-                "[1,1,3,1,1]
-[1,1,5,1,1]
-
-[[1],[2,3,4]]
-[[1],4]
-
-[9]
-[[8,7,6]]
-
-[[4,4],4,4]
-[[4,4],4,4,4]
-
-[7,7,7,7]
-[7,7,7]
-
-[]
-[3]
-
-[[[]]]
-[[]]
-
-[1,[2,[3,[4,[5,6,7]]]],8,9]
-[1,[2,[3,[4,[5,6,0]]]],8,9]"
-            )),
-            13
-        );
-    }
-
-    #[test]
-    fn d13p2() {
+    fn d13_parse_error() {
         assert_eq!(
-            Day13::part2(Day13::parse(
-                "[1,1,3,1,1]
-[1,1,5,1,1]
-
-[[1],[2,3,4]]
-[[1],4]
-
-[9]
-[[8,7,6]]
-
-[[4,4],4,4]
-[[4,4],4,4,4]
-
-[7,7,7,7]
-[7,7,7]
-
-[]
-[3]
-
-[[[]]]
-[[]]
-
-[1,[2,[3,[4,[5,6,7]]]],8,9]
-[1,[2,[3,[4,[5,6,0]]]],8,9]"
-            )),
-            140
+            Day13::parse("[1,1]\n[1,2]\n\n[1,a]\n[2]").map_err(|e| e.to_string()),
+            Err("line 4, column 1: expected a pair of packets".to_string())
         );
     }
 }
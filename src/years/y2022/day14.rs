@@ -0,0 +1,258 @@
+use nom::{character::complete::char, combinator::map, sequence::separated_pair};
+
+use crate::{
+    error::{Error, Result},
+    solver::Solver,
+    util::{
+        grid::{Dense, Grid, Pos, Sparse},
+        parse::{complete, lines, list, signed, IResult},
+    },
+    visualize::{Frame, Frames, Visualize},
+};
+
+pub struct Day14;
+
+const SOURCE: Pos = Pos::new(500, 0);
+
+fn parse_input(input: &str) -> IResult<'_, Vec<Vec<Pos>>> {
+    lines(list(
+        " -> ",
+        map(separated_pair(signed, char(','), signed), |(x, y)| {
+            Pos::new(x, y)
+        }),
+    ))(input)
+}
+
+impl<'a> Solver<'a> for Day14 {
+    type Parsed = Vec<Vec<Pos>>;
+    type Output = usize;
+
+    fn parse(input: &'a str) -> Result<Self::Parsed> {
+        complete(input, parse_input, "a rock path like `498,4 -> 498,6`")
+    }
+
+    fn part1(data: &Self::Parsed) -> Result<Self::Output> {
+        Ok(pour_into_abyss(data, |_, _| {}))
+    }
+
+    fn part2(data: &Self::Parsed) -> Result<Self::Output> {
+        pour_onto_floor(data, |_, _| {})
+    }
+}
+
+impl Visualize<'_> for Day14 {
+    fn visualize(data: &Self::Parsed, part: u8, frames: &mut Frames) -> Result<()> {
+        if part == 1 {
+            pour_into_abyss(data, |cave, grain| {
+                if cave.contains(grain) {
+                    frames.step(|| dense_frame(cave));
+                } else {
+                    frames.end(|| dense_frame(cave));
+                }
+            });
+            return Ok(());
+        }
+        let floor = rocks(data)
+            .map(|rock| rock.y)
+            .max()
+            .ok_or_else(Error::no_solution)?
+            + 2;
+        let min = Pos::new(SOURCE.x - floor - 1, 0);
+        let max = Pos::new(SOURCE.x + floor + 1, floor);
+        pour_onto_floor(data, |cave, grain| {
+            if grain == SOURCE {
+                frames.end(|| sparse_frame(cave, min, max));
+            } else {
+                frames.step(|| sparse_frame(cave, min, max));
+            }
+        })?;
+        Ok(())
+    }
+}
+
+/// Every position covered by the straight segments of the rock paths.
+fn rocks(data: &[Vec<Pos>]) -> impl Iterator<Item = Pos> + '_ {
+    data.iter()
+        .flat_map(|path| path.array_windows::<2>())
+        .flat_map(|[start, end]| {
+            let (min_y, max_y) = (start.y.min(end.y), start.y.max(end.y));
+            (start.x.min(end.x)..=start.x.max(end.x))
+                .flat_map(move |x| (min_y..=max_y).map(move |y| Pos::new(x, y)))
+        })
+}
+
+fn pour_into_abyss(data: &[Vec<Pos>], mut settled: impl FnMut(&Dense<char>, Pos)) -> usize {
+    let mut rocks = rocks(data).map(|rock| (rock, '#')).collect::<Sparse<_>>();
+    rocks.insert(SOURCE, '+');
+    let Some((min, max)) = rocks.bounds() else {
+        return 0;
+    };
+    let Some((width, height)) = (max - min + Pos::new(1, 1)).coords() else {
+        return 0;
+    };
+    let mut cave = Dense::new(width, height, '.');
+    for (rock, &c) in rocks.iter() {
+        cave[rock - min] = c;
+    }
+    let source = SOURCE - min;
+    let open = |cave: &Dense<char>, pos| cave.get(pos).is_none_or(|&c| c == '.' || c == '+');
+    (0..)
+        .map_while(|_| {
+            if cave[source] == 'o' {
+                return None;
+            }
+            let mut grain = source;
+            while cave.contains(grain) {
+                let fallen = cave
+                    .ray(grain, Pos::DOWN)
+                    .take_while(|&(_, &c)| c == '.' || c == '+')
+                    .last();
+                if let Some((pos, _)) = fallen {
+                    grain = pos;
+                }
+                if let Some(next) = [Pos::DOWN + Pos::LEFT, Pos::DOWN + Pos::RIGHT]
+                    .into_iter()
+                    .map(|step| grain + step)
+                    .find(|&next| open(&cave, next))
+                {
+                    grain = next;
+                } else if fallen.is_none() {
+                    cave[grain] = 'o';
+                    break;
+                }
+            }
+            settled(&cave, grain);
+            cave.contains(grain).then_some(grain)
+        })
+        .count()
+}
+
+fn pour_onto_floor(
+    data: &[Vec<Pos>],
+    mut settled: impl FnMut(&Sparse<char>, Pos),
+) -> Result<usize> {
+    let mut cave = rocks(data).map(|rock| (rock, '#')).collect::<Sparse<_>>();
+    let floor = cave.bounds().ok_or_else(Error::no_solution)?.1.y + 2;
+    let open = |cave: &Sparse<char>, pos: Pos| pos.y < floor && !cave.contains(pos);
+    let mut count = 0;
+    loop {
+        let mut grain = SOURCE;
+        while let Some(next) = [Pos::DOWN, Pos::DOWN + Pos::LEFT, Pos::DOWN + Pos::RIGHT]
+            .into_iter()
+            .map(|step| grain + step)
+            .find(|&next| open(&cave, next))
+        {
+            grain = next;
+        }
+        cave.insert(grain, 'o');
+        settled(&cave, grain);
+        count += 1;
+        if grain == SOURCE {
+            return Ok(count);
+        }
+    }
+}
+
+fn dense_frame(cave: &Dense<char>) -> Frame {
+    let mut frame = Frame::new(cave.width(), cave.height(), '.');
+    for (pos, &c) in cave.iter() {
+        frame.set_pos(pos, c);
+    }
+    frame
+}
+
+/// Draws the cave between `min` and `max`, with the floor along the bottom row.
+fn sparse_frame(cave: &Sparse<char>, min: Pos, max: Pos) -> Frame {
+    let (width, height) = (max - min + Pos::new(1, 1)).coords().unwrap_or_default();
+    let mut frame = Frame::new(width, height, '.');
+    (0..width).for_each(|x| frame.set(x, height.saturating_sub(1), '#'));
+    if !cave.contains(SOURCE) {
+        frame.set_pos(SOURCE - min, '+');
+    }
+    for (pos, &c) in cave.iter() {
+        frame.set_pos(pos - min, c);
+    }
+    frame
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::util::{oracle, HashSet};
+
+    /// Up to `paths` rock paths of a few straight segments each, no deeper than `depth` and in
+    /// reach of the sand falling from 500,0.
+    fn rock_paths(paths: usize, depth: u16) -> impl Strategy<Value = String> {
+        let start = (490..=510u16, 1..=depth);
+        let segments = prop::collection::vec(-5..=5i32, 1..=4);
+        let path = (start, segments).prop_map(move |((mut x, mut y), segments)| {
+            let mut points = vec![format!("{x},{y}")];
+            for (i, length) in segments.into_iter().enumerate() {
+                if i % 2 == 0 {
+                    x = u16::try_from(i32::from(x) + length).unwrap();
+                } else {
+                    y = u16::try_from((i32::from(y) + length).clamp(1, i32::from(depth))).unwrap();
+                }
+                points.push(format!("{x},{y}"));
+            }
+            points.join(" -> ")
+        });
+        prop::collection::vec(path, 1..=paths).prop_map(oracle::lines)
+    }
+
+    /// Drops sand from 500,0 one grain at a time until a grain falls below the deepest rock, or
+    /// settles on the source, resting on a floor two below the deepest rock if there is one.
+    fn pour(input: &str, floor: bool) -> Option<usize> {
+        let mut blocked = HashSet::new();
+        for line in input.lines() {
+            let points = line
+                .split(" -> ")
+                .map(|point| {
+                    let (x, y) = point.split_once(',').unwrap();
+                    (x.parse::<i32>().unwrap(), y.parse::<i32>().unwrap())
+                })
+                .collect::<Vec<_>>();
+            for pair in points.windows(2) {
+                let ((x1, y1), (x2, y2)) = (pair[0], pair[1]);
+                for x in x1.min(x2)..=x1.max(x2) {
+                    for y in y1.min(y2)..=y1.max(y2) {
+                        blocked.insert((x, y));
+                    }
+                }
+            }
+        }
+        let deepest = blocked.iter().map(|&(_, y)| y).max()?;
+        let mut grains = 0;
+        while !blocked.contains(&(500, 0)) {
+            let (mut x, mut y) = (500, 0);
+            loop {
+                if !floor && y > deepest {
+                    return Some(grains);
+                }
+                let next = [(x, y + 1), (x - 1, y + 1), (x + 1, y + 1)]
+                    .into_iter()
+                    .find(|&(x, y)| !blocked.contains(&(x, y)) && (!floor || y < deepest + 2));
+                match next {
+                    Some(fall) => (x, y) = fall,
+                    None => break,
+                }
+            }
+            blocked.insert((x, y));
+            grains += 1;
+        }
+        Some(grains)
+    }
+
+    #[test]
+    fn matches_reference() {
+        let depth = 12;
+        oracle::agree::<Day14, _, _>(
+            2000,
+            rock_paths(6, depth),
+            |input| pour(input, false),
+            |input| pour(input, true),
+        );
+    }
+}
@@ -0,0 +1,202 @@
+use nalgebra::Vector2;
+use nom::{bytes::complete::tag, combinator::map, sequence::tuple};
+
+use crate::{
+    error::{Error, Result},
+    solver::Solver,
+    util::{
+        intervals::Intervals,
+        parse::{complete, lines, xy, IResult},
+        *,
+    },
+};
+
+pub struct Day15;
+
+/// A sensor and the beacon closest to it.
+type Reading = (Vector2<i32>, Vector2<i32>);
+
+fn parse_input(i: &str) -> IResult<'_, Vec<Reading>> {
+    let position = || map(xy, |(x, y)| Vector2::new(x, y));
+    lines(map(
+        tuple((
+            tag("Sensor at "),
+            position(),
+            tag(": closest beacon is at "),
+            position(),
+        )),
+        |(_, sensor, _, beacon)| (sensor, beacon),
+    ))(i)
+}
+
+impl<'a> Solver<'a> for Day15 {
+    type Parsed = Vec<Reading>;
+    type Output = u64;
+
+    fn parse(input: &'a str) -> Result<Self::Parsed> {
+        complete(
+            input,
+            parse_input,
+            "`Sensor at x=<n>, y=<n>: closest beacon is at x=<n>, y=<n>`",
+        )
+    }
+
+    fn part1(data: &Self::Parsed) -> Result<Self::Output> {
+        let y = if data[0].0.x == 2 { 10 } else { 2_000_000 };
+        let covered = data
+            .iter()
+            .filter_map(|(sensor, beacon)| {
+                let reach = (sensor - beacon).abs().sum() - (sensor.y - y).abs();
+                (reach >= 0).then(|| sensor.x - reach..=sensor.x + reach)
+            })
+            .collect::<Intervals<_>>();
+        let beacons = data
+            .iter()
+            .filter(|(_, beacon)| beacon.y == y && covered.contains(beacon.x))
+            .map(|(_, beacon)| beacon.x)
+            .collect::<HashSet<_>>();
+        Ok(covered.len() - beacons.len() as u64)
+    }
+
+    fn part2(data: &Self::Parsed) -> Result<Self::Output> {
+        let sensor_distances = data
+            .iter()
+            .map(|(sensor, beacon)| (sensor, (sensor - beacon).abs().sum()))
+            .collect::<Vec<_>>();
+        let max = if data.first().ok_or_else(Error::no_solution)?.0.x == 2 {
+            20
+        } else {
+            4_000_000
+        };
+        let min = Vector2::repeat(0);
+        let max = Vector2::repeat(max);
+        sensor_distances
+            .iter()
+            .map(|(s, d)| (s, d + 1))
+            .flat_map(|(&&s, d)| {
+                (0..d).flat_map(move |x| {
+                    [
+                        Vector2::new(x, -(d - x)) + s,
+                        Vector2::new(d - x, x) + s,
+                        Vector2::new(-x, d - x) + s,
+                        Vector2::new(-(d - x), x) + s,
+                    ]
+                })
+            })
+            .filter(|&v| v >= min && v <= max)
+            .rfind(|v| {
+                sensor_distances
+                    .iter()
+                    .all(|&(s, d)| (v - s).abs().sum() > d)
+            })
+            .and_then(|v| Some(u64::try_from(v.x).ok()? * 4_000_000 + u64::try_from(v.y).ok()?))
+            .ok_or_else(Error::no_solution)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::util::oracle;
+
+    fn distance((ax, ay): (i32, i32), (bx, by): (i32, i32)) -> i32 {
+        (ax - bx).abs() + (ay - by).abs()
+    }
+
+    /// Inputs on the scale of the example, which the solver recognises by its first sensor at
+    /// x=2: part 1 looks at y=10 and the distress beacon is somewhere in 0..=20. Up to `sensors`
+    /// random sensors miss a random distress beacon, and more sensors are then added to cover
+    /// every other position.
+    fn sensors(sensors: usize) -> impl Strategy<Value = String> {
+        let sensor = ((-5..=25, -5..=25), 0..100i32, -100..=100i32);
+        let first = (Just(2), -5..=25);
+        (
+            0..=20,
+            0..=20,
+            (first, 0..100i32, -100..=100i32),
+            prop::collection::vec(sensor, 0..sensors),
+        )
+            .prop_filter_map(
+                "sensors must not sit on the distress beacon",
+                |(x, y, first, rest)| {
+                    let hidden = (x, y);
+                    let mut pairs = Vec::new();
+                    for (sensor, range, direction) in std::iter::once(first).chain(rest) {
+                        let reach = distance(sensor, hidden) - 1;
+                        if reach < 0 {
+                            return None;
+                        }
+                        // A beacon at most `reach` away, in a direction picked by `direction`,
+                        // both given in percent.
+                        let r = reach * range / 100;
+                        let dx = r * direction / 100;
+                        let dy = (r - dx.abs()) * if direction < 0 { -1 } else { 1 };
+                        pairs.push((sensor, (sensor.0 + dx, sensor.1 + dy)));
+                    }
+                    for y in 0..=20 {
+                        for x in 0..=20 {
+                            let covered = pairs
+                                .iter()
+                                .any(|&(s, b)| distance((x, y), s) <= distance(s, b));
+                            if (x, y) != hidden && !covered {
+                                let reach = distance((x, y), hidden) - 1;
+                                pairs.push(((x, y), (x + reach, y)));
+                            }
+                        }
+                    }
+                    Some(oracle::lines(pairs.iter().map(|((sx, sy), (bx, by))| {
+                        format!("Sensor at x={sx}, y={sy}: closest beacon is at x={bx}, y={by}")
+                    })))
+                },
+            )
+    }
+
+    fn pairs(input: &str) -> Vec<((i32, i32), (i32, i32))> {
+        let numbers = regex::Regex::new(r"-?\d+").unwrap();
+        input
+            .lines()
+            .map(|line| {
+                let n = numbers
+                    .find_iter(line)
+                    .map(|n| n.as_str().parse().unwrap())
+                    .collect::<Vec<i32>>();
+                ((n[0], n[1]), (n[2], n[3]))
+            })
+            .collect()
+    }
+
+    fn reference1(input: &str) -> Option<u64> {
+        let pairs = pairs(input);
+        let y = 10;
+        let reach = pairs.iter().map(|&(s, b)| distance(s, b)).max()?;
+        let min = pairs.iter().map(|&(s, _)| s.0).min()? - reach;
+        let max = pairs.iter().map(|&(s, _)| s.0).max()? + reach;
+        Some(
+            (min..=max)
+                .filter(|&x| pairs.iter().all(|&(_, b)| b != (x, y)))
+                .filter(|&x| {
+                    pairs
+                        .iter()
+                        .any(|&(s, b)| distance((x, y), s) <= distance(s, b))
+                })
+                .count() as u64,
+        )
+    }
+
+    fn reference2(input: &str) -> Option<u64> {
+        let pairs = pairs(input);
+        let mut uncovered = (0..=20)
+            .flat_map(|y| (0..=20).map(move |x| (x, y)))
+            .filter(|&p| pairs.iter().all(|&(s, b)| distance(p, s) > distance(s, b)));
+        uncovered
+            .next()
+            .map(|(x, y)| u64::try_from(x * 4_000_000 + y).unwrap())
+    }
+
+    #[test]
+    fn matches_reference() {
+        oracle::agree::<Day15, _, _>(1000, sensors(8), reference1, reference2);
+    }
+}
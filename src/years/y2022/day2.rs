@@ -1,4 +1,14 @@
-use crate::{solver::Solver, util::*};
+use nom::{
+    character::complete::{char, one_of},
+    combinator::{map, recognize},
+    sequence::separated_pair,
+};
+
+use crate::{
+    error::Result,
+    solver::Solver,
+    util::parse::{complete, expect, lines},
+};
 
 pub struct Day2;
 
@@ -31,11 +41,11 @@ impl Choice {
         ((self as u32 + 2) % 3).try_into().unwrap()
     }
 
-    fn looses(self, other: Choice) -> bool {
+    fn looses(self, other: Self) -> bool {
         other == self.winner()
     }
 
-    fn value(self) -> u32 {
+    const fn value(self) -> u32 {
         self as u32 + 1
     }
 }
@@ -53,8 +63,7 @@ impl TryFrom<&str> for Choice {
     }
 }
 
-fn score(pair: &[Choice; 2]) -> u32 {
-    let [a, b] = pair.to_owned();
+fn score([a, b]: [Choice; 2]) -> u32 {
     let selected = b.value();
     let outcome = if b.looses(a) {
         0
@@ -70,23 +79,33 @@ impl<'a> Solver<'a> for Day2 {
     type Parsed = Vec<[&'a str; 2]>;
     type Output = u32;
 
-    fn parse(input: &'a str) -> Self::Parsed {
-        input
-            .split("\n")
-            .flat_map(|x| x.splitn(2, " "))
-            .array_chunks::<2>()
-            .collect()
+    fn parse(input: &'a str) -> Result<Self::Parsed> {
+        let choice = |choices, expected| expect(expected, recognize(one_of(choices)));
+        complete(
+            input,
+            lines(map(
+                separated_pair(
+                    choice("ABC", "`A`, `B` or `C`"),
+                    char(' '),
+                    choice("XYZ", "`X`, `Y` or `Z`"),
+                ),
+                <[_; 2]>::from,
+            )),
+            "two space-separated choices",
+        )
     }
 
-    fn part1(data: Self::Parsed) -> Self::Output {
-        data.iter()
+    fn part1(data: &Self::Parsed) -> Result<Self::Output> {
+        Ok(data
+            .iter()
             .map(|chunk| chunk.map(|x| Choice::try_from(x).unwrap()))
-            .map(|chunk| score(&chunk))
-            .sum()
+            .map(score)
+            .sum())
     }
 
-    fn part2(data: Self::Parsed) -> Self::Output {
-        data.iter()
+    fn part2(data: &Self::Parsed) -> Result<Self::Output> {
+        Ok(data
+            .iter()
             .map(|chunk| {
                 let a = Choice::try_from(chunk[0]).unwrap();
                 match chunk[1] {
@@ -95,8 +114,8 @@ impl<'a> Solver<'a> for Day2 {
                     _ => [a, a],
                 }
             })
-            .map(|chunk| score(&chunk))
-            .sum()
+            .map(score)
+            .sum())
     }
 }
 
@@ -134,42 +153,13 @@ mod tests {
 
     #[test]
     fn test_score() {
-        [Choice::Rock, Choice::Paper, Choice::Scissor]
-            .iter()
-            .for_each(|&choice| {
-                assert_eq!(
-                    score(&[choice, choice.looser()]),
-                    choice.looser().value() + 0
-                );
-                assert_eq!(score(&[choice, choice]), choice.value() + 3);
-                assert_eq!(
-                    score(&[choice, choice.winner()]),
-                    choice.winner().value() + 6
-                );
-            });
-    }
-
-    #[test]
-    fn d2p1() {
-        assert_eq!(
-            Day2::part1(Day2::parse(
-                "A Y
-B X
-C Z"
-            )),
-            15
-        );
-    }
-
-    #[test]
-    fn d2p2() {
-        assert_eq!(
-            Day2::part2(Day2::parse(
-                "A Y
-B X
-C Z"
-            )),
-            12
-        );
+        for choice in [Choice::Rock, Choice::Paper, Choice::Scissor] {
+            assert_eq!(score([choice, choice.looser()]), choice.looser().value());
+            assert_eq!(score([choice, choice]), choice.value() + 3);
+            assert_eq!(
+                score([choice, choice.winner()]),
+                choice.winner().value() + 6
+            );
+        }
     }
 }
@@ -1,8 +1,14 @@
-use crate::solver::Solver;
+use nom::character::complete::alpha1;
+
+use crate::{
+    error::{Error, Result},
+    solver::Solver,
+    util::parse::{complete, lines},
+};
 
 pub struct Day3;
 
-fn get_priority(c: char) -> u32 {
+const fn get_priority(c: char) -> u32 {
     c as u32
         - if c.is_lowercase() {
             'a' as u32 - 1
@@ -15,26 +21,26 @@ impl<'a> Solver<'a> for Day3 {
     type Parsed = Vec<&'a str>;
     type Output = u32;
 
-    fn parse(input: &'a str) -> Self::Parsed {
-        input.trim().split("\n").collect()
+    fn parse(input: &'a str) -> Result<Self::Parsed> {
+        complete(input, lines(alpha1), "an item letter")
     }
 
-    fn part1(data: Self::Parsed) -> Self::Output {
-        data.into_iter()
+    fn part1(data: &Self::Parsed) -> Result<Self::Output> {
+        data.iter()
             .map(|rucksack| {
                 let (first, second) = rucksack.split_at(rucksack.len() / 2);
                 first
                     .chars()
                     .filter(|&c| second.contains(c))
                     .map(get_priority)
-                    .nth(0)
-                    .unwrap()
+                    .next()
+                    .ok_or_else(Error::no_solution)
             })
             .sum()
     }
 
-    fn part2(data: Self::Parsed) -> Self::Output {
-        data.into_iter()
+    fn part2(data: &Self::Parsed) -> Result<Self::Output> {
+        data.iter()
             .array_chunks::<3>()
             .map(|group| {
                 group[0]
@@ -42,8 +48,8 @@ impl<'a> Solver<'a> for Day3 {
                     .filter(|&c| group[1].contains(c))
                     .filter(|&c| group[2].contains(c))
                     .map(get_priority)
-                    .nth(0)
-                    .unwrap()
+                    .next()
+                    .ok_or_else(Error::no_solution)
             })
             .sum()
     }
@@ -60,34 +66,4 @@ mod tests {
         assert_eq!(get_priority('A'), 27);
         assert_eq!(get_priority('Z'), 52);
     }
-
-    #[test]
-    fn d3p1() {
-        assert_eq!(
-            Day3::part1(Day3::parse(
-                "vJrwpWtwJgWrhcsFMMfFFhFp
-jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
-PmmdzqPrVvPwwTWBwg
-wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
-ttgJtRGJQctTZtZT
-CrZsJsPPZsGzwwsLwLmpwMDw"
-            )),
-            157
-        );
-    }
-
-    #[test]
-    fn d3p2() {
-        assert_eq!(
-            Day3::part2(Day3::parse(
-                "vJrwpWtwJgWrhcsFMMfFFhFp
-jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
-PmmdzqPrVvPwwTWBwg
-wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
-ttgJtRGJQctTZtZT
-CrZsJsPPZsGzwwsLwLmpwMDw"
-            )),
-            70
-        );
-    }
 }
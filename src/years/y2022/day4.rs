@@ -0,0 +1,91 @@
+use nom::{character::complete::char, combinator::map, sequence::separated_pair};
+
+use crate::{
+    error::Result,
+    solver::{BytesSolver, Solver},
+    util::{
+        bytes::Scanner,
+        parse::{complete, expect, lines, unsigned},
+    },
+};
+
+pub struct Day4;
+
+/// Whether the assignment `outer` holds every section of `inner`.
+const fn covers(outer: (u8, u8), inner: (u8, u8)) -> bool {
+    outer.0 <= inner.0 && inner.1 <= outer.1
+}
+
+impl<'a> Solver<'a> for Day4 {
+    type Parsed = Vec<[(u8, u8); 2]>;
+    type Output = usize;
+
+    fn parse(input: &'a str) -> Result<Self::Parsed> {
+        let range = || {
+            let id = || expect("a section id", unsigned::<u8>);
+            separated_pair(id(), char('-'), id())
+        };
+        complete(
+            input,
+            lines(map(
+                separated_pair(range(), char(','), range()),
+                <[_; 2]>::from,
+            )),
+            "a section id",
+        )
+    }
+
+    fn part1(data: &Self::Parsed) -> Result<Self::Output> {
+        Ok(data
+            .iter()
+            .filter(|&&[a, b]| covers(a, b) || covers(b, a))
+            .count())
+    }
+
+    fn part2(data: &Self::Parsed) -> Result<Self::Output> {
+        Ok(data
+            .iter()
+            .filter(|&&[(a, b), (c, d)]| a <= d && c <= b)
+            .count())
+    }
+}
+
+impl<'a> BytesSolver<'a> for Day4 {
+    fn parse_bytes(input: &'a [u8]) -> Result<Self::Parsed> {
+        let range = |scanner: &mut Scanner| -> Result<(u8, u8)> {
+            let start = scanner.integer("a section id")?;
+            scanner.byte(b'-', "`-`")?;
+            Ok((start, scanner.integer("a section id")?))
+        };
+        Scanner::new(input).lines(|scanner| {
+            let first = range(scanner)?;
+            scanner.byte(b',', "`,`")?;
+            Ok([first, range(scanner)?])
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn d4_parse_error() {
+        assert_eq!(
+            Day4::parse("2-4,6-8\n2-3,4-x").map_err(|e| e.to_string()),
+            Err("line 2, column 7: expected a section id".to_string())
+        );
+        assert_eq!(
+            Day4::parse("2-4,6").map_err(|e| e.to_string()),
+            Err("line 1, column 6: expected a section id".to_string())
+        );
+        assert_eq!(
+            Day4::parse_bytes(b"2-4,6-8\n2-3,4-x").map_err(|e| e.to_string()),
+            Err("line 2, column 7: expected a section id".to_string())
+        );
+        assert_eq!(
+            Day4::parse_bytes(b"2-4,6").map_err(|e| e.to_string()),
+            Err("line 1, column 6: expected `-`".to_string())
+        );
+    }
+}
@@ -0,0 +1,254 @@
+use std::fmt::Display;
+
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{anychar, char, newline, space0, space1},
+    combinator::{map, map_opt, value},
+    multi::{many1, separated_list1},
+    sequence::{delimited, preceded, terminated},
+};
+
+use crate::{
+    error::{Error, Result},
+    solver::Solver,
+    util::parse::{complete, expect, lines, list, unsigned, IResult, ParseError},
+    visualize::{Frame, Frames, Visualize},
+};
+
+pub struct Day5;
+
+#[derive(Debug, Clone, Copy)]
+struct Container {
+    mark: char,
+}
+
+type Stack = Vec<Container>;
+
+#[derive(Debug, Clone)]
+pub struct Port {
+    stacks: Vec<Stack>,
+}
+
+impl Display for Port {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.stacks
+            .iter()
+            .map(|stack| stack.iter().map(|c| c.mark))
+            .enumerate()
+            .try_for_each(|(i, stack)| writeln!(f, "{}: {}", i, stack.collect::<String>()))
+    }
+}
+#[derive(Debug, Clone, Copy)]
+pub struct Move {
+    count: u8,
+    from: u8,
+    to: u8,
+}
+
+impl Move {
+    fn execute(self, port: &mut Port) {
+        let from = &mut port.stacks[self.from as usize];
+        assert!(from.len() >= self.count as usize);
+        let i = from.len() - self.count as usize;
+        let drain = from.drain(i..).rev().collect::<Vec<_>>();
+        port.stacks[self.to as usize].extend(drain);
+    }
+
+    fn execute_9001(self, port: &mut Port) {
+        let from = &mut port.stacks[self.from as usize];
+        assert!(from.len() >= self.count as usize);
+        let i = from.len() - self.count as usize;
+        let drain = from.drain(i..).collect::<Vec<_>>();
+        port.stacks[self.to as usize].extend(drain);
+    }
+}
+
+impl Display for Move {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} × {} → {}", self.count, self.from, self.to)
+    }
+}
+
+/// A crate `[A]` or the three spaces where there is none.
+fn slot(input: &str) -> IResult<'_, Option<Container>> {
+    alt((
+        map(delimited(char('['), anychar, char(']')), |mark| {
+            Some(Container { mark })
+        }),
+        value(None, tag("   ")),
+    ))(input)
+}
+
+/// The rows of crates from the top down, then the line of stack numbers under them.
+fn drawing(input: &str) -> IResult<'_, Port> {
+    let (input, rows) = many1(terminated(list(" ", slot), newline))(input)?;
+    let (input, numbers) = expect(
+        "a line of stack numbers",
+        preceded(space0, separated_list1(space1, unsigned::<u8>)),
+    )(input)?;
+    let (input, _) = space0(input)?;
+    let mut stacks = vec![Stack::new(); numbers.len()];
+    for row in rows.iter().rev() {
+        for (stack, slot) in stacks.iter_mut().zip(row) {
+            stack.extend(*slot);
+        }
+    }
+    Ok((input, Port { stacks }))
+}
+
+/// The moves, checked against the heights of the stacks they leave, which are the same whichever
+/// crane moves the crates. A stack that isn't drawn or a count that its stack can't supply fails
+/// at the number.
+fn crane_moves<'a>(mut heights: Vec<usize>) -> impl FnMut(&'a str) -> IResult<'a, Vec<Move>> {
+    lines(move |input| {
+        let stacks = heights.len();
+        let stack = || {
+            expect(
+                format!("a stack number from 1 to {stacks}"),
+                map_opt(unsigned::<u8>, |id| {
+                    id.checked_sub(1).filter(|&i| usize::from(i) < stacks)
+                }),
+            )
+        };
+        let (counted, _) = tag("move ")(input)?;
+        let (rest, count) = expect("a crate count", unsigned::<u8>)(counted)?;
+        let (rest, from) = preceded(tag(" from "), stack())(rest)?;
+        let (rest, to) = preceded(tag(" to "), stack())(rest)?;
+        let available = heights[usize::from(from)];
+        if available < usize::from(count) {
+            return Err(nom::Err::Failure(ParseError::new(
+                counted,
+                format!("a crate count of at most {available}"),
+            )));
+        }
+        heights[usize::from(from)] -= usize::from(count);
+        heights[usize::from(to)] += usize::from(count);
+        Ok((rest, Move { count, from, to }))
+    })
+}
+
+impl<'a> Solver<'a> for Day5 {
+    type Parsed = (Port, Vec<Move>);
+    type Output = String;
+
+    fn parse(input: &'a str) -> Result<Self::Parsed> {
+        complete(
+            input,
+            |input| {
+                let (input, port) = drawing(input)?;
+                let (input, _) =
+                    expect("a blank line between stacks and moves", tag("\n\n"))(input)?;
+                let heights = port.stacks.iter().map(Vec::len).collect();
+                let (input, moves) = crane_moves(heights)(input)?;
+                Ok((input, (port, moves)))
+            },
+            "`move <n> from <n> to <n>`",
+        )
+    }
+
+    fn part1(data: &Self::Parsed) -> Result<Self::Output> {
+        Self::part1_owned(data.clone())
+    }
+
+    fn part2(data: &Self::Parsed) -> Result<Self::Output> {
+        Self::part2_owned(data.clone())
+    }
+
+    fn part1_owned(data: Self::Parsed) -> Result<Self::Output> {
+        let (port, moves) = data;
+        top_marks(&rearrange(port, &moves, Move::execute, |_| {}))
+    }
+
+    fn part2_owned(data: Self::Parsed) -> Result<Self::Output> {
+        let (port, moves) = data;
+        top_marks(&rearrange(port, &moves, Move::execute_9001, |_| {}))
+    }
+}
+
+impl Visualize<'_> for Day5 {
+    fn visualize(data: &Self::Parsed, part: u8, frames: &mut Frames) -> Result<()> {
+        let (initial, moves) = data.clone();
+        let crane = if part == 1 {
+            Move::execute
+        } else {
+            Move::execute_9001
+        };
+        let mut height = 0;
+        rearrange(initial.clone(), &moves, crane, |port| {
+            height = port.stacks.iter().map(Vec::len).fold(height, usize::max);
+        });
+        frames.step(|| port_frame(&initial, height));
+        let mut left = moves.len();
+        rearrange(initial, &moves, crane, |port| {
+            left -= 1;
+            if left == 0 {
+                frames.end(|| port_frame(port, height));
+            } else {
+                frames.step(|| port_frame(port, height));
+            }
+        });
+        Ok(())
+    }
+}
+
+fn rearrange(
+    mut port: Port,
+    moves: &[Move],
+    crane: fn(Move, &mut Port),
+    mut observe: impl FnMut(&Port),
+) -> Port {
+    for &mov in moves {
+        crane(mov, &mut port);
+        observe(&port);
+    }
+    port
+}
+
+fn top_marks(port: &Port) -> Result<String> {
+    port.stacks
+        .iter()
+        .map(|stack| stack.last().map(|container| container.mark))
+        .collect::<Option<_>>()
+        .ok_or_else(Error::no_solution)
+}
+
+/// Draws the stacks side by side as `[A]` columns, `height` containers tall.
+fn port_frame(port: &Port, height: usize) -> Frame {
+    let mut frame = Frame::new((port.stacks.len() * 4).saturating_sub(1), height, ' ');
+    for (i, stack) in port.stacks.iter().enumerate() {
+        for (level, container) in stack.iter().enumerate() {
+            let y = height - 1 - level;
+            frame.set(i * 4, y, '[');
+            frame.set(i * 4 + 1, y, container.mark);
+            frame.set(i * 4 + 2, y, ']');
+        }
+    }
+    frame
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DRAWING: &str = "    [D]    \n[N] [C]    \n[Z] [M] [P]\n 1   2   3 \n\n";
+
+    fn error(moves: &str) -> String {
+        Day5::parse(&format!("{DRAWING}{moves}"))
+            .unwrap_err()
+            .to_string()
+    }
+
+    #[test]
+    fn d5_impossible_moves() {
+        assert_eq!(
+            error("move 1 from 9 to 1\n"),
+            "line 6, column 13: expected a stack number from 1 to 3"
+        );
+        assert_eq!(
+            error("move 1 from 2 to 1\nmove 5 from 3 to 1\n"),
+            "line 7, column 6: expected a crate count of at most 1"
+        );
+        assert!(Day5::parse(&format!("{DRAWING}move 2 from 1 to 3\nmove 3 from 3 to 2")).is_ok());
+    }
+}
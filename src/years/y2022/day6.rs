@@ -0,0 +1,87 @@
+use nom::{character::complete::alpha1, combinator::map};
+
+use crate::{
+    error::{Error, Result},
+    solver::{Solver, Variant},
+    util::{parse::complete, *},
+};
+
+pub struct Day6;
+
+/// Slides a window over the stream, keeping a count of each letter in it.
+pub struct SlidingWindow;
+
+impl<'a> Solver<'a> for Day6 {
+    type Parsed = Vec<char>;
+    type Output = usize;
+
+    fn parse(input: &'a str) -> Result<Self::Parsed> {
+        complete(
+            input,
+            map(alpha1, |stream: &str| stream.chars().collect()),
+            "a datastream of letters",
+        )
+    }
+
+    fn part1(data: &Self::Parsed) -> Result<Self::Output> {
+        let mut set = HashSet::<char>::with_capacity(4);
+        data.windows(4)
+            .enumerate()
+            .skip_while(|&(_, window)| {
+                set.clear();
+                set.extend(window);
+                set.len() < 4
+            })
+            .map(|(i, _)| i + 4)
+            .next()
+            .ok_or_else(Error::no_solution)
+    }
+
+    fn part2(data: &Self::Parsed) -> Result<Self::Output> {
+        let mut set = HashSet::<char>::with_capacity(14);
+        data.windows(14)
+            .enumerate()
+            .skip_while(|&(_, window)| {
+                set.clear();
+                set.extend(window);
+                set.len() < 14
+            })
+            .map(|(i, _)| i + 14)
+            .next()
+            .ok_or_else(Error::no_solution)
+    }
+}
+
+impl Variant<'_, Day6> for SlidingWindow {
+    fn part1(data: &Vec<char>) -> Result<usize> {
+        marker(data, 4)
+    }
+
+    fn part2(data: &Vec<char>) -> Result<usize> {
+        marker(data, 14)
+    }
+}
+
+/// The number of characters read up to the end of the first `size` distinct ones in a row.
+fn marker(data: &[char], size: usize) -> Result<usize> {
+    let mut counts = [0u8; 128];
+    let mut repeated = 0;
+    for (i, &c) in data.iter().enumerate() {
+        let count = &mut counts[c as usize & 127];
+        *count += 1;
+        if *count == 2 {
+            repeated += 1;
+        }
+        if i >= size {
+            let count = &mut counts[data[i - size] as usize & 127];
+            *count -= 1;
+            if *count == 1 {
+                repeated -= 1;
+            }
+        }
+        if i + 1 >= size && repeated == 0 {
+            return Ok(i + 1);
+        }
+    }
+    Err(Error::no_solution())
+}
@@ -1,6 +1,18 @@
-use std::{collections::BTreeMap, str::FromStr};
-
-use crate::{solver::Solver, util::*};
+use std::collections::BTreeMap;
+
+use nom::{
+    branch::alt,
+    bytes::complete::{is_not, tag},
+    character::complete::char,
+    combinator::{map, value},
+    sequence::{preceded, separated_pair},
+};
+
+use crate::{
+    error::{Error, Result},
+    solver::Solver,
+    util::parse::{complete, expect, lines, unsigned, IResult},
+};
 
 pub struct Day7;
 
@@ -10,28 +22,28 @@ pub enum Token {
     FileInfo(FileInfo),
 }
 
-impl FromStr for Token {
-    type Err = String;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut iter = s.split_whitespace();
-        if s.starts_with("$") {
-            iter.next();
-            let name = iter.next().unwrap();
-            let path = iter.next();
-            match name {
-                "cd" => Ok(Command::Cd(path.unwrap().to_string())),
-                "ls" => Ok(Command::Ls),
-                _ => Err(format!("{name} is not a command")),
-            }
-            .map(|cmd| Self::Command(cmd))
-        } else {
-            let size = iter.next().unwrap().parse::<usize>().ok();
-            let name = iter.next().unwrap().to_string();
-            let dir = size.is_none();
-            Ok(Self::FileInfo(FileInfo { size, name, dir }))
-        }
-    }
+/// A line of the terminal output: `$ cd <path>`, `$ ls`, `dir <name>` or `<size> <name>`.
+fn token(input: &str) -> IResult<'_, Token> {
+    let name = |expected| expect(expected, map(is_not("\n"), str::to_string));
+    alt((
+        map(preceded(tag("$ cd "), name("a path after `cd`")), |path| {
+            Token::Command(Command::Cd(path))
+        }),
+        value(Token::Command(Command::Ls), tag("$ ls")),
+        map(preceded(tag("dir "), name("a directory name")), |name| {
+            Token::FileInfo(FileInfo::new_dir(&name))
+        }),
+        map(
+            separated_pair(unsigned, char(' '), name("a file name")),
+            |(size, name)| {
+                Token::FileInfo(FileInfo {
+                    size: Some(size),
+                    name,
+                    dir: false,
+                })
+            },
+        ),
+    ))(input)
 }
 
 #[derive(Debug, Clone)]
@@ -48,8 +60,8 @@ pub struct FileInfo {
 }
 
 impl FileInfo {
-    fn new_dir(name: &str) -> FileInfo {
-        FileInfo {
+    fn new_dir(name: &str) -> Self {
+        Self {
             size: None,
             name: name.to_string(),
             dir: true,
@@ -64,6 +76,49 @@ struct FileSystem {
 }
 
 impl FileSystem {
+    fn from_tokens(tokens: &[Token]) -> Self {
+        let mut file_system = tokens.iter().fold(Self::new(), |mut file_system, token| {
+            match token {
+                Token::Command(cmd) => {
+                    if let Command::Cd(dir) = cmd {
+                        file_system.change_dir(dir);
+                    }
+                }
+                Token::FileInfo(info) => {
+                    if info.dir {
+                        file_system.add_dir(&info.name);
+                    } else {
+                        file_system.add_file(info);
+                    }
+                }
+            }
+            file_system
+        });
+        file_system.calculate_sizes();
+        file_system
+    }
+
+    fn small_dirs_size(&self) -> usize {
+        self.files
+            .values()
+            .filter(|info| info.dir && info.size.unwrap() <= 100_000)
+            .map(|info| info.size.unwrap())
+            .sum()
+    }
+
+    fn dir_to_delete_size(&self) -> Result<usize> {
+        let total_available = 70_000_000;
+        let total_needed = 30_000_000;
+        let unused = total_available - self.files.get("/").unwrap().size.unwrap();
+        let required = total_needed - unused;
+        self.files
+            .values()
+            .filter(|info| info.dir && info.size.unwrap() >= required)
+            .map(|info| info.size.unwrap())
+            .min()
+            .ok_or_else(Error::no_solution)
+    }
+
     fn new() -> Self {
         Self {
             files: BTreeMap::from([("/".to_string(), FileInfo::new_dir("/"))]),
@@ -82,7 +137,7 @@ impl FileSystem {
             dir => {
                 self.cwd.push(dir.to_string());
             }
-        };
+        }
     }
 
     fn cwd_str(&self) -> String {
@@ -115,7 +170,7 @@ impl FileSystem {
             .map(|(path, info)| (path.clone(), info.clone()))
             .collect::<Vec<_>>();
         dirs.sort_unstable_by(|(a, _), (b, _)| b.cmp(a));
-        dirs.iter().for_each(|(path, _)| {
+        for (path, _) in &dirs {
             let (from, mut to) = (path.clone(), path.clone());
             to.replace_range(
                 to.len() - 1..,
@@ -132,7 +187,7 @@ impl FileSystem {
                 .sum();
             let dir = self.files.get_mut(path).unwrap();
             dir.size = Some(total_size);
-        });
+        }
     }
 }
 
@@ -140,74 +195,32 @@ impl<'a> Solver<'a> for Day7 {
     type Parsed = Vec<Token>;
     type Output = usize;
 
-    fn parse(input: &'a str) -> Self::Parsed {
-        input
-            .lines()
-            .flat_map(|line| Token::from_str(line))
-            .collect()
+    fn parse(input: &'a str) -> Result<Self::Parsed> {
+        complete(
+            input,
+            lines(expect(
+                "`$ cd <path>`, `$ ls`, `dir <name>` or `<size> <name>`",
+                token,
+            )),
+            "a line of terminal output",
+        )
+    }
+
+    fn part1(data: &Self::Parsed) -> Result<Self::Output> {
+        Ok(FileSystem::from_tokens(data).small_dirs_size())
     }
 
-    fn part1(data: Self::Parsed) -> Self::Output {
-        let mut file_system = data
-            .iter()
-            .fold(FileSystem::new(), |mut file_system, token| {
-                match token {
-                    Token::Command(cmd) => {
-                        if let Command::Cd(dir) = cmd {
-                            file_system.change_dir(dir);
-                        }
-                    }
-                    Token::FileInfo(info) => {
-                        if info.dir {
-                            file_system.add_dir(&info.name)
-                        } else {
-                            file_system.add_file(info)
-                        }
-                    }
-                };
-                file_system
-            });
-        file_system.calculate_sizes();
-        file_system
-            .files
-            .values()
-            .filter(|info| info.dir && info.size.unwrap() <= 100000)
-            .map(|info| info.size.unwrap())
-            .sum()
+    fn part2(data: &Self::Parsed) -> Result<Self::Output> {
+        FileSystem::from_tokens(data).dir_to_delete_size()
     }
 
-    fn part2(data: Self::Parsed) -> Self::Output {
-        let mut file_system = data
-            .iter()
-            .fold(FileSystem::new(), |mut file_system, token| {
-                match token {
-                    Token::Command(cmd) => {
-                        if let Command::Cd(dir) = cmd {
-                            file_system.change_dir(dir);
-                        }
-                    }
-                    Token::FileInfo(info) => {
-                        if info.dir {
-                            file_system.add_dir(&info.name)
-                        } else {
-                            file_system.add_file(info)
-                        }
-                    }
-                };
-                file_system
-            });
-        file_system.calculate_sizes();
-        let total_available = 70_000_000;
-        let total_needed = 30_000_000;
-        let unused = total_available - file_system.files.get("/").unwrap().size.unwrap();
-        let required = total_needed - unused;
-        file_system
-            .files
-            .values()
-            .filter(|info| info.dir && info.size.unwrap() >= required)
-            .map(|info| info.size.unwrap())
-            .min()
-            .unwrap()
+    fn solve_both(data: &Self::Parsed) -> Option<Result<(Self::Output, Self::Output)>> {
+        let file_system = FileSystem::from_tokens(data);
+        Some(
+            file_system
+                .dir_to_delete_size()
+                .map(|size| (file_system.small_dirs_size(), size)),
+        )
     }
 }
 
@@ -228,68 +241,4 @@ mod tests {
         fs.change_dir("..");
         assert_eq!(fs.cwd_str(), "/");
     }
-
-    #[test]
-    fn d7p1() {
-        assert_eq!(
-            Day7::part1(Day7::parse(
-                "$ cd /
-$ ls
-dir a
-14848514 b.txt
-8504156 c.dat
-dir d
-$ cd a
-$ ls
-dir e
-29116 f
-2557 g
-62596 h.lst
-$ cd e
-$ ls
-584 i
-$ cd ..
-$ cd ..
-$ cd d
-$ ls
-4060174 j
-8033020 d.log
-5626152 d.ext
-7214296 k"
-            )),
-            95437
-        );
-    }
-
-    #[test]
-    fn d7p2() {
-        assert_eq!(
-            Day7::part2(Day7::parse(
-                "$ cd /
-$ ls
-dir a
-14848514 b.txt
-8504156 c.dat
-dir d
-$ cd a
-$ ls
-dir e
-29116 f
-2557 g
-62596 h.lst
-$ cd e
-$ ls
-584 i
-$ cd ..
-$ cd ..
-$ cd d
-$ ls
-4060174 j
-8033020 d.log
-5626152 d.ext
-7214296 k"
-            )),
-            24933642
-        );
-    }
 }
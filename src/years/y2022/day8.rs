@@ -0,0 +1,137 @@
+use crate::{
+    error::{Error, Result},
+    solver::Solver,
+    util::{
+        grid::{Dense, Pos},
+        parse::{complete, grid},
+    },
+};
+
+pub struct Day8;
+
+fn is_visible(trees: &Dense<u8>, pos: Pos, height: u8) -> bool {
+    Pos::ORTHOGONAL
+        .into_iter()
+        .any(|step| trees.ray(pos, step).all(|(_, &tree)| tree < height))
+}
+
+fn scenic_score(trees: &Dense<u8>, pos: Pos, height: u8) -> usize {
+    Pos::ORTHOGONAL
+        .into_iter()
+        .map(|step| viewing_distance(height, trees.ray(pos, step).map(|(_, &tree)| tree)))
+        .product()
+}
+
+/// The number of trees seen along `line`, up to and including the first one at least as tall.
+fn viewing_distance(height: u8, line: impl Iterator<Item = u8>) -> usize {
+    let mut distance = 0;
+    for tree in line {
+        distance += 1;
+        if tree >= height {
+            break;
+        }
+    }
+    distance
+}
+
+impl<'a> Solver<'a> for Day8 {
+    type Parsed = Dense<u8>;
+    type Output = usize;
+
+    fn parse(input: &'a str) -> Result<Self::Parsed> {
+        let trees = grid("trees", "a tree height", |c| {
+            c.to_digit(10).and_then(|height| u8::try_from(height).ok())
+        });
+        complete(input, trees, "a row of trees")
+    }
+
+    fn part1(data: &Self::Parsed) -> Result<Self::Output> {
+        Ok(data
+            .iter()
+            .filter(|&(pos, &height)| is_visible(data, pos, height))
+            .count())
+    }
+
+    fn part2(data: &Self::Parsed) -> Result<Self::Output> {
+        data.iter()
+            .map(|(pos, &height)| scenic_score(data, pos, height))
+            .max()
+            .ok_or_else(Error::no_solution)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::util::oracle;
+
+    /// Forests of up to `size` by `size` trees.
+    fn forest(size: usize) -> impl Strategy<Value = String> {
+        (1..=size, 1..=size).prop_flat_map(|(rows, cols)| {
+            prop::collection::vec(prop::collection::vec(0..=9u8, cols), rows).prop_map(|rows| {
+                oracle::lines(
+                    rows.iter()
+                        .map(|row| row.iter().map(u8::to_string).collect::<String>()),
+                )
+            })
+        })
+    }
+
+    fn heights(input: &str) -> Vec<Vec<u8>> {
+        input
+            .lines()
+            .map(|line| line.bytes().map(|b| b - b'0').collect())
+            .collect()
+    }
+
+    /// The trees seen from (`x`, `y`) in each direction, nearest first.
+    fn lines_of_sight(trees: &[Vec<u8>], x: usize, y: usize) -> [Vec<u8>; 4] {
+        let column = trees.iter().map(|row| row[x]).collect::<Vec<_>>();
+        [
+            trees[y][..x].iter().rev().copied().collect(),
+            trees[y][x + 1..].to_vec(),
+            column[..y].iter().rev().copied().collect(),
+            column[y + 1..].to_vec(),
+        ]
+    }
+
+    fn reference1(input: &str) -> usize {
+        let trees = heights(input);
+        let cells = (0..trees.len()).flat_map(|y| (0..trees[0].len()).map(move |x| (x, y)));
+        cells
+            .filter(|&(x, y)| {
+                lines_of_sight(&trees, x, y)
+                    .iter()
+                    .any(|line| line.iter().all(|&h| h < trees[y][x]))
+            })
+            .count()
+    }
+
+    fn reference2(input: &str) -> Option<usize> {
+        let trees = heights(input);
+        let cells = (0..trees.len()).flat_map(|y| (0..trees[0].len()).map(move |x| (x, y)));
+        cells
+            .map(|(x, y)| {
+                lines_of_sight(&trees, x, y)
+                    .iter()
+                    .map(|line| {
+                        let blocked = line.iter().position(|&h| h >= trees[y][x]);
+                        blocked.map_or(line.len(), |i| i + 1)
+                    })
+                    .product()
+            })
+            .max()
+    }
+
+    #[test]
+    fn matches_reference() {
+        oracle::agree::<Day8, _, _>(
+            2000,
+            forest(12),
+            |input| Some(reference1(input)),
+            reference2,
+        );
+    }
+}
@@ -0,0 +1,187 @@
+use std::str::FromStr;
+
+use nalgebra::Vector2;
+use nom::{
+    character::complete::{anychar, char},
+    combinator::{map, map_res, recognize},
+    sequence::separated_pair,
+};
+
+use crate::{
+    error::Result,
+    solver::{BytesSolver, Solver},
+    util::{
+        bytes::Scanner,
+        parse::{complete, expect, lines, signed},
+        *,
+    },
+    visualize::{Frame, Frames, Visualize},
+};
+
+pub struct Day9;
+
+#[derive(Debug, Clone, Copy)]
+enum Direction {
+    Right,
+    Up,
+    Left,
+    Down,
+}
+
+impl FromStr for Direction {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "R" => Ok(Self::Right),
+            "U" => Ok(Self::Up),
+            "L" => Ok(Self::Left),
+            "D" => Ok(Self::Down),
+            _ => Err(format!("`{s}` is not a direction")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Motion {
+    dir: Direction,
+    amount: i32,
+}
+
+impl From<Motion> for Vector2<i32> {
+    fn from(value: Motion) -> Self {
+        match value.dir {
+            Direction::Right => Self::new(value.amount, 0),
+            Direction::Up => Self::new(0, value.amount),
+            Direction::Left => Self::new(-value.amount, 0),
+            Direction::Down => Self::new(0, -value.amount),
+        }
+    }
+}
+
+impl<'a> Solver<'a> for Day9 {
+    type Parsed = Vec<Motion>;
+    type Output = usize;
+
+    fn parse(input: &'a str) -> Result<Self::Parsed> {
+        let motion = separated_pair(
+            expect(
+                "`R`, `U`, `L` or `D`",
+                map_res(recognize(anychar), str::parse),
+            ),
+            char(' '),
+            expect("an amount", signed),
+        );
+        complete(
+            input,
+            lines(map(motion, |(dir, amount)| Motion { dir, amount })),
+            "a direction and an amount",
+        )
+    }
+
+    fn part1(data: &Self::Parsed) -> Result<Self::Output> {
+        Ok(simulate(data, 2, |_| {}).len())
+    }
+
+    fn part2(data: &Self::Parsed) -> Result<Self::Output> {
+        Ok(simulate(data, 10, |_| {}).len())
+    }
+}
+
+impl<'a> BytesSolver<'a> for Day9 {
+    fn parse_bytes(input: &'a [u8]) -> Result<Self::Parsed> {
+        Scanner::new(input).lines(|scanner| {
+            let dir = scanner.map("`R`, `U`, `L` or `D`", |byte| match byte {
+                b'R' => Some(Direction::Right),
+                b'U' => Some(Direction::Up),
+                b'L' => Some(Direction::Left),
+                b'D' => Some(Direction::Down),
+                _ => None,
+            })?;
+            scanner.byte(b' ', "` `")?;
+            let amount = scanner.integer("an amount")?;
+            Ok(Motion { dir, amount })
+        })
+    }
+}
+
+impl Visualize<'_> for Day9 {
+    fn visualize(data: &Self::Parsed, part: u8, frames: &mut Frames) -> Result<()> {
+        let knots = if part == 1 { 2 } else { 10 };
+        let (min, max) = data
+            .iter()
+            .scan(Vector2::new(0, 0), |head, &motion| {
+                *head += Vector2::from(motion);
+                Some(*head)
+            })
+            .fold((Vector2::zeros(), Vector2::zeros()), |(min, max), head| {
+                (min.inf(&head), max.sup(&head))
+            });
+        // Offsets from `min` are never negative.
+        let unsigned = |n: i32| usize::try_from(n).unwrap_or_default();
+        let size = (max - min).map(|n| unsigned(n) + 1);
+        let cell = |v: Vector2<i32>| {
+            let v = (v - min).map(unsigned);
+            (v.x, size.y - 1 - v.y)
+        };
+        let mut trail = Frame::new(size.x, size.y, '.');
+        let steps = data.iter().map(|motion| motion.amount).sum::<i32>();
+        let mut step = 0;
+        simulate(data, knots, |rope| {
+            let (x, y) = cell(*rope.last().unwrap());
+            trail.set(x, y, '#');
+            let frame = || {
+                let mut frame = trail.clone();
+                let (x, y) = cell(Vector2::zeros());
+                frame.set(x, y, 's');
+                for (i, &knot) in rope.iter().enumerate().rev() {
+                    let (x, y) = cell(knot);
+                    let c = if i == 0 {
+                        'H'
+                    } else {
+                        char::from_digit(u32::try_from(i).unwrap(), 10).unwrap()
+                    };
+                    frame.set(x, y, c);
+                }
+                frame
+            };
+            step += 1;
+            if step == steps {
+                frames.end(frame);
+            } else {
+                frames.step(frame);
+            }
+        });
+        Ok(())
+    }
+}
+
+/// Pulls a rope of `knots` knots along the motions of its head, showing every step to `moved`,
+/// and returns the positions its tail visited.
+fn simulate(
+    data: &[Motion],
+    knots: usize,
+    mut moved: impl FnMut(&[Vector2<i32>]),
+) -> HashSet<Vector2<i32>> {
+    let mut rope = vec![Vector2::new(0, 0); knots];
+    let mut visited = HashSet::new();
+    visited.insert(rope[knots - 1]);
+    for motion in data {
+        let step = Vector2::from(Motion {
+            dir: motion.dir,
+            amount: 1,
+        });
+        for _ in 0..motion.amount {
+            rope[0] += step;
+            for i in 1..knots {
+                let diff = rope[i - 1] - rope[i];
+                if diff.dot(&diff) > 2 {
+                    rope[i] += Vector2::new(diff[0].clamp(-1, 1), diff[1].clamp(-1, 1));
+                }
+            }
+            visited.insert(rope[knots - 1]);
+            moved(&rope);
+        }
+    }
+    visited
+}
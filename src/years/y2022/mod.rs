@@ -0,0 +1,46 @@
+use crate::registry::Registry;
+
+pub mod day1;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+
+pub const YEAR: u16 = 2022;
+
+pub fn register(registry: &mut Registry) {
+    registry
+        .register_bytes::<day1::Day1>(YEAR, 1)
+        .register::<day2::Day2>(YEAR, 2)
+        .register::<day3::Day3>(YEAR, 3)
+        .register_bytes::<day4::Day4>(YEAR, 4)
+        .register::<day5::Day5>(YEAR, 5)
+        .register::<day6::Day6>(YEAR, 6)
+        .register::<day7::Day7>(YEAR, 7)
+        .register::<day8::Day8>(YEAR, 8)
+        .register_bytes::<day9::Day9>(YEAR, 9)
+        .register_bytes::<day10::Day10>(YEAR, 10)
+        .register::<day11::Day11>(YEAR, 11)
+        .register::<day12::Day12>(YEAR, 12)
+        .register::<day13::Day13>(YEAR, 13)
+        .register::<day14::Day14>(YEAR, 14)
+        .register::<day15::Day15>(YEAR, 15);
+    registry
+        .variant::<day1::Day1, day1::Select>(YEAR, 1, "select")
+        .variant::<day6::Day6, day6::SlidingWindow>(YEAR, 6, "window");
+    registry
+        .visualize::<day5::Day5>(YEAR, 5)
+        .visualize::<day9::Day9>(YEAR, 9)
+        .visualize::<day12::Day12>(YEAR, 12)
+        .visualize::<day14::Day14>(YEAR, 14);
+}
@@ -0,0 +1,74 @@
+use std::{fs, path::Path};
+
+use advent_of_code::{answers::diff, years};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+struct Expected {
+    part1: Option<String>,
+    part2: Option<String>,
+}
+
+fn check(year: u16, day: u8, example: &str, part: u8) {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join(format!("examples/{year}/day{day}"));
+    let input = fs::read_to_string(dir.join(format!("{example}.txt"))).unwrap();
+    let expected: Expected =
+        toml::from_str(&fs::read_to_string(dir.join(format!("{example}.toml"))).unwrap()).unwrap();
+    let expected = if part == 1 {
+        expected.part1
+    } else {
+        expected.part2
+    }
+    .unwrap();
+
+    let registry = years::registry();
+    let solver = registry.get(year, day).expect("the day is registered");
+    let parsed = solver.parse(&input).unwrap();
+    let (answer, owned_answer) = if part == 1 {
+        (parsed.part1(), solver.parse(&input).unwrap().into_part1())
+    } else {
+        (parsed.part2(), solver.parse(&input).unwrap().into_part2())
+    };
+    let answer = answer.unwrap();
+    assert_eq!(
+        owned_answer.unwrap(),
+        answer,
+        "the consuming and borrowing forms disagree"
+    );
+    assert!(
+        answer == expected,
+        "wrong answer\n{}",
+        diff(&expected, &answer)
+    );
+
+    if solver.parses_bytes() {
+        let parsed = solver.parse_bytes(input.as_bytes()).unwrap();
+        let answer = if part == 1 {
+            parsed.part1()
+        } else {
+            parsed.part2()
+        };
+        assert_eq!(answer.unwrap(), expected, "the bytes parser disagrees");
+    }
+
+    if let Some(both) = parsed.solve_both() {
+        let both = both.unwrap();
+        assert_eq!(
+            both[usize::from(part - 1)],
+            expected,
+            "solving both parts at once disagrees"
+        );
+    }
+
+    for (name, variant) in registry.variants(year, day).skip(1) {
+        let parsed = variant.parse(&input).unwrap();
+        let answer = if part == 1 {
+            parsed.part1()
+        } else {
+            parsed.part2()
+        };
+        assert_eq!(answer.unwrap(), expected, "variant `{name}` disagrees");
+    }
+}
+
+include!(concat!(env!("OUT_DIR"), "/examples.rs"));
@@ -0,0 +1,52 @@
+//! Builds tests/ffi/solve.c against include/aoc.h and runs it on the library built as a `cdylib`.
+#![cfg(target_os = "linux")]
+
+use std::{env, path::Path, process::Command};
+
+use advent_of_code::years;
+
+#[test]
+fn c_program() {
+    // Integration tests are built in the directory of the libraries they depend on, the cdylib
+    // of this crate included.
+    let exe = env::current_exe().unwrap();
+    let deps = exe.parent().unwrap();
+    assert!(
+        deps.join("libadvent_of_code.so").exists(),
+        "no libadvent_of_code.so in {}",
+        deps.display()
+    );
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let dir = tempfile::tempdir().unwrap();
+    let program = dir.path().join("solve");
+    let compiled = Command::new(env::var_os("CC").unwrap_or_else(|| "cc".into()))
+        .args(["-std=c11", "-Wall", "-Wextra", "-Werror"])
+        .arg("-I")
+        .arg(root.join("include"))
+        .arg(root.join("tests/ffi/solve.c"))
+        .arg("-L")
+        .arg(deps)
+        .arg(format!("-Wl,-rpath,{}", deps.display()))
+        .arg("-ladvent_of_code")
+        .arg("-o")
+        .arg(&program)
+        .output()
+        .expect("a C compiler is installed as `cc`, or given in `CC`");
+    assert!(
+        compiled.status.success(),
+        "{}",
+        String::from_utf8_lossy(&compiled.stderr)
+    );
+
+    let run = Command::new(&program).output().unwrap();
+    assert!(
+        run.status.success(),
+        "{}",
+        String::from_utf8_lossy(&run.stderr)
+    );
+    let days = years::registry()
+        .days()
+        .map(|(year, day)| format!("{year} {day}\n"))
+        .collect::<String>();
+    assert_eq!(String::from_utf8(run.stdout).unwrap(), days);
+}
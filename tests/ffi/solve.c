@@ -0,0 +1,48 @@
+/* Solves the example of 2022 day 1 through the C interface, and lists the days on stdout. Run by
+ * tests/ffi.rs, it exits with 1 after printing any unexpected result on stderr. */
+
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+
+#include "aoc.h"
+
+static const char EXAMPLE[] =
+    "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000\n";
+
+static int failures = 0;
+
+static void check(const char *what, const char *input, uint16_t day, uint8_t part, size_t out_len,
+                  AocStatus expected_status, const char *expected) {
+    char out[128];
+    AocStatus status =
+        aoc_solve(2022, day, part, (const uint8_t *)input, strlen(input), out, out_len);
+    if (status != expected_status || (expected != NULL && strcmp(out, expected) != 0)) {
+        fprintf(stderr, "%s: got %d `%s`, expected %d `%s`\n", what, status,
+                status == AOC_STATUS_UNKNOWN_DAY ? "" : out, expected_status,
+                expected != NULL ? expected : "");
+        failures++;
+    }
+}
+
+int main(void) {
+    size_t count = aoc_days(NULL, 0);
+    AocDay *days = calloc(count, sizeof *days);
+    if (days == NULL || aoc_days(days, count) != count) {
+        fprintf(stderr, "could not list the days\n");
+        return 1;
+    }
+    for (size_t i = 0; i < count; i++) {
+        printf("%d %d\n", days[i].year, days[i].day);
+    }
+    free(days);
+
+    check("part 1", EXAMPLE, 1, 1, 128, AOC_STATUS_OK, "24000");
+    check("part 2", EXAMPLE, 1, 2, 128, AOC_STATUS_OK, "45000");
+    check("parse error", "1000\nabc\n", 1, 1, 128, AOC_STATUS_PARSE_ERROR,
+          "line 2, column 1: expected a calorie count");
+    check("short buffer", EXAMPLE, 1, 1, 3, AOC_STATUS_BUFFER_TOO_SMALL, "24");
+    check("unknown day", EXAMPLE, 26, 1, 128, AOC_STATUS_UNKNOWN_DAY, NULL);
+    check("part 3", EXAMPLE, 1, 3, 128, AOC_STATUS_INVALID_ARGUMENT, NULL);
+    return failures == 0 ? 0 : 1;
+}